@@ -20,6 +20,12 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
   let mut ty_list: Vec<Expr> = Vec::new();
   let mut list = None::<syn::ExprArray>;
   let mut has_default = false;
+  let mut try_from = false;
+  let mut const_fn = false;
+  let mut proptest = false;
+  let mut rand = false;
+  let mut locale_files: Vec<(Ident, syn::LitStr)> = Vec::new();
+  let mut named_lists: Vec<(Ident, syn::ExprArray)> = Vec::new();
 
   if let Some(attr) = ast.attrs.iter().find(|x| x.path().is_ident("isomorphism")) {
 
@@ -28,6 +34,18 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
       if meta.path.is_ident("has_default") {
         has_default = true;
 
+      } else if meta.path.is_ident("try_from") {
+        try_from = true;
+
+      } else if meta.path.is_ident("const_fn") {
+        const_fn = true;
+
+      } else if meta.path.is_ident("proptest") {
+        proptest = true;
+
+      } else if meta.path.is_ident("rand") {
+        rand = true;
+
       } else if meta.path.is_ident("list") {
         let arg: syn::ExprArray = meta.value()?.parse()?;
         list.replace(arg);
@@ -38,6 +56,26 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
           ty_list.push(arg);
         }
 
+      } else if meta.path.is_ident("lists") {
+        meta.parse_nested_meta(|meta| {
+          let Some(list_name) = meta.path.get_ident().cloned() else {
+            return Err(meta.error("`lists` has arguments of `<name> = [..]`"));
+          };
+          let arg: syn::ExprArray = meta.value()?.parse()?;
+          named_lists.push((list_name, arg));
+          Ok(())
+        })?;
+
+      } else if meta.path.is_ident("locale") {
+        meta.parse_nested_meta(|meta| {
+          let Some(locale) = meta.path.get_ident().cloned() else {
+            return Err(meta.error("`locale` has arguments of `<locale> = \"<file path>\"`"));
+          };
+          let path: syn::LitStr = meta.value()?.parse()?;
+          locale_files.push((locale, path));
+          Ok(())
+        })?;
+
       } else if let Some(arg) = meta.path.get_ident().map(|x| x.clone()) {
           ty.replace(arg);
 
@@ -52,17 +90,37 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
   if ty.is_some() && !ty_list.is_empty() {
     return Err(Error::new(ast.span(), "To pass Into/From type, should either use format of simple ident or array."));
   }
+  // either or neither of From or TryFrom
+  if has_default && try_from {
+    return Err(Error::new(ast.span(), "`has_default` (From) and `try_from` (TryFrom) cannot be used together."));
+  }
+  let has_from = has_default || try_from;
 
   // get enum data
   let data = match &ast.data {
     // struct => just impl Isomorphism trait
     Data::Struct(_) => {
-      let gen = quote! {
+      let mut gen = quote! {
         impl #impl_generics Isomorphism for #name #ty_generics #where_clause {
           fn title(&self) -> &str { "" }
           fn list() -> Vec<Self> { Vec::new() }
         }
       };
+      if cfg!(feature = "cli") {
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::ValueEnum for #name #ty_generics #where_clause { }
+        });
+      }
+      if cfg!(feature = "export") {
+        let name_str = name.to_string();
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::IsomorphismExport for #name #ty_generics #where_clause {
+            fn mapping_table() -> ::seoul::MappingTable {
+              ::seoul::MappingTable { name: #name_str, columns: Vec::new(), rows: Vec::new() }
+            }
+          }
+        });
+      }
       return Ok(gen.into());
     },
     // enum
@@ -72,6 +130,23 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
     _ => return Err(Error::new(ast.span(), "Only for Enum data type.")),
   };
 
+  // localized titles from files: (locale, [(variant, title)])
+  let mut locale_tables: Vec<(String, Vec<(String, String)>)> = Vec::new();
+  let mut quoted_locale_include = TokenStream::new();
+  for (locale, path) in locale_files.iter() {
+    let table = read_locale_file(path)?;
+    for (variant, _) in table.iter() {
+      if !data.variants.iter().any(|x| x.ident==variant) {
+        return Err(Error::new(path.span(), format!("Unknown variant `{}` in locale file \"{}\"", variant, path.value())));
+      }
+    }
+    locale_tables.push((locale.to_string(), table));
+    // let cargo track the file
+    quoted_locale_include.extend(quote! {
+      const _: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #path));
+    });
+  }
+
   // fallback list
   let mut fallback_list: Option<TokenStream> = if list.is_none() { Some(TokenStream::new()) } else { None };
 
@@ -81,6 +156,16 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
   let mut quoted_into_list: Vec<TokenStream> = (0..len).map(|_| TokenStream::new()).collect();
   let mut quoted_from_list: Vec<TokenStream> = (0..len).map(|_| TokenStream::new()).collect();
   let mut quoted_title  = TokenStream::new();
+  let mut quoted_description = TokenStream::new();
+  let mut quoted_export_rows = TokenStream::new();
+  let mut variant_titles: Vec<TokenStream> = Vec::new();
+  let mut quoted_title_in = TokenStream::new();
+  let mut quoted_aliases = TokenStream::new();
+  let mut quoted_tags = TokenStream::new();
+  // (tag, default formats of tagged variants), in order of appearance
+  let mut tagged: Vec<(String, TokenStream)> = Vec::new();
+  // (matching format, default format, is unit variant, into values) for const fns
+  let mut variant_values: Vec<(TokenStream, TokenStream, bool, Vec<Expr>)> = Vec::new();
 
 
   for variant in data.variants.iter() {
@@ -92,6 +177,12 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
 
     let mut values: Vec<Expr> = Vec::new();
     let mut title = None::<Expr>;
+    let mut localized: Localized = Vec::new();
+    let mut description = None::<Expr>;
+    let mut aliases: Vec<syn::LitStr> = Vec::new();
+    let mut tags: Vec<String> = Vec::new();
+    // extra patterns for From/TryFrom, for each into type
+    let mut patterns: Vec<Vec<syn::Pat>> = Vec::new();
 
     for attr in variant.attrs.iter() {
 
@@ -108,18 +199,68 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
         }
 
       } else if attr.path().is_ident("title") {
+        let (arg, args) = parse_title(attr)?;
+        if let Some(arg) = arg {
+          title.replace(arg);
+        }
+        localized.extend(args);
+
+      } else if attr.path().is_ident("description") {
         let arg: Expr = attr.parse_args()?;
-        title.replace(arg);
+        description.replace(arg);
+
+      } else if attr.path().is_ident("alias") {
+        let args = attr.parse_args_with(syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated)?;
+        aliases.extend(args);
+
+      } else if attr.path().is_ident("tags") {
+        let args = attr.parse_args_with(syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated)?;
+        for arg in args.iter().map(|x| x.to_string()) {
+          if !tags.contains(&arg) {
+            tags.push(arg);
+          }
+        }
+
+      } else if attr.path().is_ident("from") {
+        if !has_from {
+          return Err(Error::new(attr.span(), "Variant level `from` needs `has_default` (From) or `try_from` (TryFrom) at top level attribute."));
+        }
+        if ty.is_some() {
+          let arg = attr.parse_args_with(syn::Pat::parse_multi_with_leading_vert)?;
+          patterns.push(vec![arg]);
+
+        } else if !ty_list.is_empty() {
+          let args = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let content;
+            syn::bracketed!(content in input);
+            syn::punctuated::Punctuated::<syn::Pat, syn::Token![,]>::parse_terminated_with(&content, syn::Pat::parse_multi_with_leading_vert)
+          })?;
+          if args.len() != ty_list.len() {
+            return Err(Error::new(attr.span(), "Using Into/From type arrays, should give a pattern for each type."));
+          }
+          patterns.push(args.into_iter().collect());
+        }
       }
     };
 
+    // into value given, or fallback to into type's default value (one-way decoding with `from`)
+    let into_given = !values.is_empty();
+
     // Into, From
     if !ty_list.is_empty() {
+      if !into_given && !patterns.is_empty() {
+        for ty in ty_list.iter() {
+          values.push(syn::parse_quote! { <#ty>::default() });
+        }
+      }
       if ty_list.len() != values.len() {
         return Err(Error::new(ast.span(), "Using Into/From type arrays, should not omit values."));
       }
     }
 
+    let is_unit = matches!(variant.fields, Fields::Unit);
+    variant_values.push((matching_format.clone(), default_format.clone(), is_unit, values.clone()));
+
     // Ident ty
     if let Some(ty) = ty.as_ref() {
       // Into
@@ -132,12 +273,16 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
       ));
 
       // From
-      if has_default {
+      if has_from {
         if let Some(value) = values.first() {
           quoted_from_list.get_mut(0).map(|x| x.extend(
             quote! { #value => #default_format, }
           ));
         };
+        for pats in patterns.iter() {
+          let pat = &pats[0];
+          quoted_from_list[0].extend(quote! { #pat => #default_format, });
+        }
       }
 
     // List ty
@@ -147,23 +292,100 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
         quoted_into.extend(
           quote! { #matching_format => #value, }
         );
-        if has_default {
+        if has_from && into_given {
           quoted_from.extend(
             quote! { #value => #default_format, }
           );
         }
       }
+      if has_from {
+        for pats in patterns.iter() {
+          for (quoted_from, pat) in quoted_from_list.iter_mut().zip(pats.iter()) {
+            quoted_from.extend(quote! { #pat => #default_format, });
+          }
+        }
+      }
     }
 
     // title
-    quoted_title.extend(
-      if let Some(title) = title {
-        quote! { #matching_format =>  #title, }
+    let variant_name = &variant.ident.to_string();
+    let title = if let Some(title) = title {
+      quote! { #title }
+    } else {
+      quote! { #variant_name }
+    };
+    quoted_title.extend(quote! { #matching_format => #title, });
+    variant_titles.push(title.clone());
+
+    // localized titles: attribute first, then locale files
+    for (locale, table) in locale_tables.iter() {
+      if localized.iter().any(|(x, _)| x==locale) {
+        continue;
+      }
+      if let Some((_, value)) = table.iter().find(|(x, _)| variant.ident==x) {
+        localized.push((locale.clone(), syn::parse_quote! { #value }));
+      }
+    }
+    for (locale, value) in localized.iter() {
+      quoted_title_in.extend(quote! { (#matching_format, #locale) => #value, });
+    }
+
+    // description
+    let description = if let Some(description) = description {
+      quote! { Some(#description) }
+    } else {
+      quote! { None }
+    };
+    quoted_description.extend(quote! { #matching_format => #description, });
+
+    // aliases
+    quoted_aliases.extend(quote! { #matching_format => &[#(#aliases),*], });
+
+    // tags
+    quoted_tags.extend(quote! { #matching_format => &[#(#tags),*], });
+    for tag in tags.iter() {
+      if let Some((_, list)) = tagged.iter_mut().find(|(x, _)| x==tag) {
+        list.extend(quote! { #default_format, });
       } else {
-        let variant_name = &variant.ident.to_string();
-        quote! {#matching_format => #variant_name, }
+        tagged.push((tag.clone(), quote! { #default_format, }));
       }
-    );
+    }
+
+    // export row: evaluate each into value with its type
+    if cfg!(feature = "export") {
+      let mut quoted_values = TokenStream::new();
+      if let Some(ty) = ty.as_ref() {
+        let value = values.first().map(|x| quote! { #x }).unwrap_or(quote! { #ty::default() });
+        quoted_values.extend(quote! { ::seoul::ToExportValue::to_export_value(&{ let v: #ty = #value; v }), });
+      } else {
+        for (ty, value) in ty_list.iter().zip(values.iter()) {
+          quoted_values.extend(quote! { ::seoul::ToExportValue::to_export_value(&{ let v: #ty = #value; v }), });
+        }
+      }
+      quoted_export_rows.extend(quote! {
+        ::seoul::MappingRow {
+          variant: #variant_name,
+          title: String::from(#title),
+          values: vec![#quoted_values],
+          description: Option::<&str>::map(#description, String::from),
+        },
+      });
+    }
+  };
+
+  // export: column names of into types
+  let export_columns: Vec<String> = if let Some(ty) = ty.as_ref() {
+    vec![ty.to_string()]
+  } else {
+    ty_list.iter().map(|x| x.to_token_stream().to_string()).collect()
+  };
+
+
+  // into types, for const fns
+  let targets: Vec<Expr> = if let Some(ty) = ty.as_ref() {
+    vec![syn::parse_quote! { #ty }]
+  } else {
+    ty_list.clone()
   };
 
 
@@ -216,6 +438,35 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
         }
       });
     }
+    // TryFrom, returning the unmatched value as error
+    if try_from {
+      quoted.extend(quote! {
+
+        impl #impl_generics TryFrom<#ty> for #name #ty_generics #where_clause {
+          type Error = #ty;
+          fn try_from(value: #ty) -> Result<Self, Self::Error> {
+            #[allow(unreachable_patterns)]
+            let x = match &value {
+              #quoted_from
+              _ => return Err(value)
+            };
+            Ok(x)
+          }
+        }
+
+        impl #ref_impl_generics TryFrom<&'a #ty> for #name #ty_generics #where_clause {
+          type Error = &'a #ty;
+          fn try_from(value: &'a #ty) -> Result<Self, Self::Error> {
+            #[allow(unreachable_patterns)]
+            let x = match value {
+              #quoted_from
+              _ => return Err(value)
+            };
+            Ok(x)
+          }
+        }
+      });
+    }
   };
 
   if let Some(ty) = ty {
@@ -233,7 +484,7 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
   // list
   let mut quoted_list = TokenStream::new();
 
-  if let Some(list) = list {
+  if let Some(list) = list.as_ref() {
     for expr in list.elems.iter() {
       quoted_list.extend(
         quote! { Self::#expr, }
@@ -243,6 +494,9 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
     quoted_list = fallback_list.unwrap();
   }
 
+  // tagged lists
+  let (tagged_names, tagged_lists): (Vec<String>, Vec<TokenStream>) = tagged.into_iter().unzip();
+
   // list, title
   quoted.extend(quote! {
     impl #impl_generics Isomorphism for #name #ty_generics #where_clause {
@@ -254,8 +508,235 @@ pub fn impl_isomorphism_macro(ast: &DeriveInput) -> Result<TokenStream> {
       fn list() -> Vec<Self> {
         vec![#quoted_list]
       }
+      fn description(&self) -> Option<&str> {
+        match self {
+          #quoted_description
+        }
+      }
+      fn title_in(&self, locale: &str) -> &str {
+        #quoted_locale_include
+        #[allow(unreachable_patterns)]
+        match (self, locale) {
+          #quoted_title_in
+          _ => self.title()
+        }
+      }
+      fn tags(&self) -> &[&str] {
+        match self {
+          #quoted_tags
+        }
+      }
+      fn list_tagged(tag: &str) -> Vec<Self> {
+        match tag {
+          #(#tagged_names => vec![#tagged_lists],)*
+          _ => Vec::new()
+        }
+      }
     }
   });
 
+  // ValueEnum trait
+  if cfg!(feature = "cli") {
+    quoted.extend(quote! {
+      impl #impl_generics ::seoul::ValueEnum for #name #ty_generics #where_clause {
+        fn aliases(&self) -> &[&str] {
+          match self {
+            #quoted_aliases
+          }
+        }
+      }
+    });
+  }
+
+  // inherent fns
+  let mut quoted_inherent = TokenStream::new();
+
+  // const fns `to_<target>` and `from_<target>` (with `const_fn`), for targets whose values are all const expressions
+
+  for (i, target) in targets.iter().enumerate().filter(|_| const_fn) {
+    let Expr::Path(target_path) = target else { continue };
+    let Some(target_ident) = target_path.path.segments.last().map(|x| &x.ident) else { continue };
+    if !variant_values.iter().all(|(_, _, _, values)| values.get(i).is_some_and(is_const_expr)) {
+      continue;
+    }
+
+    let snake = snake_case(&target_ident.to_string());
+    let to_fn = Ident::new(&format!("to_{}", snake), target_ident.span());
+    let from_fn = Ident::new(&format!("from_{}", snake), target_ident.span());
+
+    let mut quoted_to = TokenStream::new();
+    let mut quoted_from = TokenStream::new();
+    let mut can_match = true;
+
+    for (matching_format, default_format, is_unit, values) in variant_values.iter() {
+      let value = &values[i];
+      quoted_to.extend(quote! { #matching_format => #value, });
+      // every fieldless variant should be matched back
+      if *is_unit {
+        if is_pattern(value) {
+          quoted_from.extend(quote! { #value => Some(#default_format), });
+        } else {
+          can_match = false;
+        }
+      }
+    }
+
+    let vis = &ast.vis;
+    quoted_inherent.extend(quote! {
+      #vis const fn #to_fn(&self) -> #target {
+        match self {
+          #quoted_to
+        }
+      }
+    });
+    if can_match {
+      quoted_inherent.extend(quote! {
+        #vis const fn #from_fn(value: #target) -> Option<Self> {
+          #[allow(unreachable_patterns)]
+          match value {
+            #quoted_from
+            _ => None
+          }
+        }
+      });
+    }
+  }
+
+  // named lists `list_<name>()`
+  for (list_name, list) in named_lists.iter() {
+    let list_fn = Ident::new(&format!("list_{}", list_name), list_name.span());
+    let elems = list.elems.iter();
+    let vis = &ast.vis;
+    quoted_inherent.extend(quote! {
+      #vis fn #list_fn() -> Vec<Self> {
+        vec![#(Self::#elems,)*]
+      }
+    });
+  }
+
+  if !quoted_inherent.is_empty() {
+    quoted.extend(quote! {
+      impl #impl_generics #name #ty_generics #where_clause {
+        #quoted_inherent
+      }
+    });
+  }
+
+  // proptest, rand
+  quoted.extend(impl_sampling(ast, data, list.as_ref(), proptest, rand)?);
+
+  // Transition trait
+  if let Some(quoted_transition) = impl_transition(ast, data, &variant_titles)? {
+    quoted.extend(quoted_transition);
+  }
+
+  // IsomorphismExport trait
+  if cfg!(feature = "export") {
+    let name_str = name.to_string();
+    quoted.extend(quote! {
+      impl #impl_generics ::seoul::IsomorphismExport for #name #ty_generics #where_clause {
+        fn mapping_table() -> ::seoul::MappingTable {
+          ::seoul::MappingTable {
+            name: #name_str,
+            columns: vec![#(#export_columns),*],
+            rows: vec![#quoted_export_rows],
+          }
+        }
+      }
+    });
+  }
+
   Ok(quoted.into())
-}
\ No newline at end of file
+}
+
+
+/// [(locale, localized title)]
+type Localized = Vec<(String, Expr)>;
+
+/// parse `#[title("default", <locale> = "localized", ..)]`
+/// * return (default title, localized titles)
+fn parse_title(attr: &syn::Attribute) -> Result<(Option<Expr>, Localized)> {
+
+  let args = attr.parse_args_with(syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated)?;
+
+  let mut title = None::<Expr>;
+  let mut localized: Localized = Vec::new();
+
+  for (i, arg) in args.into_iter().enumerate() {
+    match arg {
+      Expr::Assign(assign) => {
+        let locale = match assign.left.as_ref() {
+          Expr::Path(path) if path.path.get_ident().is_some() => path.path.get_ident().unwrap().to_string(),
+          Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => lit.value(),
+          left => return Err(Error::new(left.span(), "Locale of a title should be an ident or a string literal")),
+        };
+        localized.push((locale, *assign.right));
+      },
+      arg if i==0 => {
+        title.replace(arg);
+      },
+      arg => {
+        return Err(Error::new(arg.span(), "Localized titles should be given in format of `<locale> = \"title\"`"));
+      }
+    }
+  }
+
+  Ok((title, localized))
+}
+
+
+/// read a locale file of `<Variant> = <title>` lines, relative to the crate's manifest directory
+/// * blank lines and lines starting with `#` are skipped
+fn read_locale_file(path: &syn::LitStr) -> Result<Vec<(String, String)>> {
+
+  let dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+  let full_path = std::path::Path::new(&dir).join(path.value());
+
+  let content = std::fs::read_to_string(&full_path)
+    .map_err(|err| Error::new(path.span(), format!("Cannot read locale file \"{}\": {}", full_path.display(), err)))?;
+
+  let mut table: Vec<(String, String)> = Vec::new();
+  for (i, line) in content.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let Some((key, value)) = line.split_once('=') else {
+      return Err(Error::new(path.span(), format!("Locale file \"{}\" line {}: expected `<Variant> = <title>`", path.value(), i+1)));
+    };
+    table.push((key.trim().to_string(), value.trim().to_string()));
+  }
+  Ok(table)
+}
+
+
+/// whether an into value can be evaluated in a const fn:
+/// literals, paths, and tuples/arrays/casts/negations of them
+fn is_const_expr(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(_) | Expr::Path(_) => true,
+    Expr::Unary(x) => matches!(x.op, syn::UnOp::Neg(_)) && is_const_expr(&x.expr),
+    Expr::Paren(x) => is_const_expr(&x.expr),
+    Expr::Group(x) => is_const_expr(&x.expr),
+    Expr::Cast(x) => is_const_expr(&x.expr),
+    Expr::Tuple(x) => x.elems.iter().all(is_const_expr),
+    Expr::Array(x) => x.elems.iter().all(is_const_expr),
+    _ => false,
+  }
+}
+
+/// whether an into value can be a pattern of a const fn's `match`:
+/// literals (not str, byte str, ..), negated number literals and const paths
+/// * casts and other expressions are not patterns
+fn is_pattern(expr: &Expr) -> bool {
+  match expr {
+    Expr::Lit(x) => matches!(&x.lit, syn::Lit::Int(_) | syn::Lit::Float(_) | syn::Lit::Bool(_) | syn::Lit::Char(_) | syn::Lit::Byte(_)),
+    Expr::Unary(x) if matches!(x.op, syn::UnOp::Neg(_)) => {
+      matches!(x.expr.as_ref(), Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(_) | syn::Lit::Float(_), .. }))
+    },
+    Expr::Path(x) => x.qself.is_none(),
+    Expr::Paren(x) => is_pattern(&x.expr),
+    Expr::Group(x) => is_pattern(&x.expr),
+    _ => false,
+  }
+}
@@ -1,6 +1,12 @@
 mod isomorphism;
 use isomorphism::*;
 
+mod sampling;
+use sampling::*;
+
+mod transition;
+use transition::*;
+
 mod tuplike;
 use tuplike::*;
 
@@ -34,7 +40,7 @@ pub fn reflica_macro_derive(input: proc_macro::TokenStream) -> proc_macro::Token
 }
 
 
-#[proc_macro_derive(Tuplike)]
+#[proc_macro_derive(Tuplike, attributes(tuplike))]
 pub fn tuplike_macro_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let ast = syn::parse(input).unwrap();
 
@@ -44,7 +50,7 @@ pub fn tuplike_macro_derive(input: proc_macro::TokenStream) -> proc_macro::Token
 }
 
 
-#[proc_macro_derive(Isomorphism, attributes(isomorphism, into, title))]
+#[proc_macro_derive(Isomorphism, attributes(isomorphism, into, title, description, transition, alias, from, tags))]
 pub fn isomorphism_macro_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let ast = syn::parse(input).unwrap();
 
@@ -114,4 +120,24 @@ fn variant_default_format(ty_name: &Ident, variant: &syn::Variant) -> Result<Tok
     #ty_name::#variant_name #quoted_fields
   };
   Ok(gen.into())
-}
\ No newline at end of file
+}
+
+
+/// `StaticStr` -> `static_str`, `HTTPCode` -> `http_code`
+fn snake_case(s: &str) -> String {
+  let chars: Vec<char> = s.chars().collect();
+  let mut out = String::new();
+  for (i, c) in chars.iter().enumerate() {
+    if c.is_uppercase() {
+      let prev_lower = i > 0 && (chars[i-1].is_lowercase() || chars[i-1].is_ascii_digit());
+      let next_lower = i > 0 && chars.get(i+1).is_some_and(|x| x.is_lowercase()) && chars[i-1].is_uppercase();
+      if (prev_lower || next_lower) && !out.ends_with('_') {
+        out.push('_');
+      }
+      out.extend(c.to_lowercase());
+    } else {
+      out.push(*c);
+    }
+  }
+  out
+}
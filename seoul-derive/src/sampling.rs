@@ -0,0 +1,161 @@
+use crate::*;
+
+/// `proptest` (`Arbitrary`) and `rand` (`Distribution<Self>` of `StandardUniform`) implementations
+/// * opted in by top level `proptest` / `rand`, and skipped when the feature is not enabled
+/// * fieldless enum: draw uniformly from `list()`
+/// * otherwise: draw a variant uniformly, and its fields from the field types' own strategies
+/// * nothing to draw from (zero variants, or empty `list`) is a compile error
+pub fn impl_sampling(ast: &DeriveInput, data: &syn::DataEnum, list: Option<&syn::ExprArray>, proptest: bool, rand: bool) -> Result<TokenStream> {
+
+  let name = &ast.ident;
+  let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
+  let fieldless = data.variants.iter().all(|x| x.fields.is_empty());
+
+  let proptest = proptest && cfg!(feature = "proptest");
+  let rand = rand && cfg!(feature = "rand");
+
+  if proptest || rand {
+    if data.variants.is_empty() {
+      return Err(Error::new(ast.span(), "`proptest`/`rand` need at least one variant to draw."));
+    }
+    if let Some(list) = list.filter(|x| fieldless && x.elems.is_empty()) {
+      return Err(Error::new(list.span(), "`proptest`/`rand` need a non-empty `list` to draw from."));
+    }
+  }
+
+  let mut gen = TokenStream::new();
+
+  // proptest
+  if proptest {
+    let mut generics = ast.generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.push(syn::parse_quote! { Self: ::std::fmt::Debug + 'static });
+    for ty in field_types(data) {
+      where_clause.predicates.push(syn::parse_quote! { #ty: ::seoul::proptest::arbitrary::Arbitrary + 'static });
+    }
+
+    let body = if fieldless {
+      quote! {
+        let len = <Self as Isomorphism>::list().len();
+        ::seoul::proptest::strategy::Strategy::boxed(
+          ::seoul::proptest::strategy::Strategy::prop_map(0..len, |i| <Self as Isomorphism>::list().into_iter().nth(i).unwrap())
+        )
+      }
+    } else {
+      let mut strategies = TokenStream::new();
+      for variant in data.variants.iter() {
+        let variant_name = &variant.ident;
+        let tys = variant.fields.iter().map(|x| &x.ty);
+        let args = expanded_arguments(&variant.fields);
+        let build = build_fields(&variant.fields);
+        strategies.extend(quote! {
+          ::seoul::proptest::strategy::Strategy::boxed(
+            ::seoul::proptest::strategy::Strategy::prop_map(
+              ::seoul::proptest::arbitrary::any::<(#(#tys,)*)>(),
+              |(#args)| #name::#variant_name #build
+            )
+          ),
+        });
+      }
+      quote! {
+        ::seoul::proptest::strategy::Strategy::boxed(
+          ::seoul::proptest::strategy::Union::new(vec![#strategies])
+        )
+      }
+    };
+
+    gen.extend(quote! {
+      impl #impl_generics ::seoul::proptest::arbitrary::Arbitrary for #name #ty_generics #where_clause {
+        type Parameters = ();
+        type Strategy = ::seoul::proptest::strategy::BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+          #body
+        }
+      }
+    });
+  }
+
+  // rand
+  if rand {
+    let mut generics = ast.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in field_types(data) {
+      where_clause.predicates.push(syn::parse_quote! { ::seoul::rand::distr::StandardUniform: ::seoul::rand::distr::Distribution<#ty> });
+    }
+
+    let body = if fieldless {
+      quote! {
+        let list = <#name #ty_generics as Isomorphism>::list();
+        let i = ::seoul::rand::Rng::random_range(rng, 0..list.len());
+        list.into_iter().nth(i).unwrap()
+      }
+    } else {
+      let mut arms = TokenStream::new();
+      for (i, variant) in data.variants.iter().enumerate() {
+        let variant_name = &variant.ident;
+        let build = match &variant.fields {
+          Fields::Named(fields) => {
+            let names = fields.named.iter().map(|x| &x.ident);
+            quote! { { #(#names: ::seoul::rand::Rng::random(rng),)* } }
+          },
+          Fields::Unnamed(fields) => {
+            let values = fields.unnamed.iter().map(|_| quote! { ::seoul::rand::Rng::random(rng) });
+            quote! { (#(#values,)*) }
+          },
+          Fields::Unit => quote! {},
+        };
+        arms.extend(quote! { #i => #name::#variant_name #build, });
+      }
+      let len = data.variants.len();
+      quote! {
+        match ::seoul::rand::Rng::random_range(rng, 0..#len) {
+          #arms
+          _ => unreachable!()
+        }
+      }
+    };
+
+    gen.extend(quote! {
+      impl #impl_generics ::seoul::rand::distr::Distribution<#name #ty_generics> for ::seoul::rand::distr::StandardUniform #where_clause {
+        fn sample<R: ::seoul::rand::Rng + ?Sized>(&self, rng: &mut R) -> #name #ty_generics {
+          #body
+        }
+      }
+    });
+  }
+
+  Ok(gen)
+}
+
+
+/// field types of every variant, without duplicates
+fn field_types(data: &syn::DataEnum) -> Vec<&syn::Type> {
+  let mut tys: Vec<&syn::Type> = Vec::new();
+  for field in data.variants.iter().flat_map(|x| x.fields.iter()) {
+    let ty_str = field.ty.to_token_stream().to_string();
+    if !tys.iter().any(|x| x.to_token_stream().to_string()==ty_str) {
+      tys.push(&field.ty);
+    }
+  }
+  tys
+}
+
+/// `f0, f1, ..`
+fn expanded_arguments(fields: &Fields) -> TokenStream {
+  let args = (0..fields.len()).map(|i| Ident::new(&format!("f{}", i), Span::call_site()));
+  quote! { #(#args,)* }
+}
+
+/// `{ a: f0, b: f1 }` or `(f0, f1)`
+fn build_fields(fields: &Fields) -> TokenStream {
+  let args = (0..fields.len()).map(|i| Ident::new(&format!("f{}", i), Span::call_site()));
+  match fields {
+    Fields::Named(fields) => {
+      let names = fields.named.iter().map(|x| &x.ident);
+      quote! { { #(#names: #args,)* } }
+    },
+    Fields::Unnamed(_) => quote! { (#(#args,)*) },
+    Fields::Unit => quote! {},
+  }
+}
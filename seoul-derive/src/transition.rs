@@ -0,0 +1,99 @@
+use crate::*;
+
+/// `Transition` trait from variant level `#[transition(to = [..])]` attributes
+/// * `titles`: title token of each variant, in the order of the variants
+/// * return None if no variant has the attribute
+pub fn impl_transition(ast: &DeriveInput, data: &syn::DataEnum, titles: &[TokenStream]) -> Result<Option<TokenStream>> {
+
+  let name = &ast.ident;
+  let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+  // collect (from-variant, to-variants)
+  let mut edges: Vec<(&syn::Variant, Vec<&syn::Variant>)> = Vec::new();
+  let mut declared = false;
+
+  for variant in data.variants.iter() {
+    let mut to: Vec<&syn::Variant> = Vec::new();
+
+    for attr in variant.attrs.iter().filter(|x| x.path().is_ident("transition")) {
+      declared = true;
+
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("to") {
+          let args: syn::ExprArray = meta.value()?.parse()?;
+          for arg in args.elems.iter() {
+            let Expr::Path(path) = arg else {
+              return Err(Error::new(arg.span(), "`transition` targets should be variant names"));
+            };
+            let Some(next) = path.path.get_ident().and_then(|x| data.variants.iter().find(|v| &v.ident==x)) else {
+              return Err(Error::new(arg.span(), format!("Unknown variant `{}` in `transition`", path.to_token_stream())));
+            };
+            if !to.iter().any(|x| x.ident==next.ident) {
+              to.push(next);
+            }
+          }
+          Ok(())
+        } else {
+          Err(meta.error("`transition` attribute has an argument of `to = [..]`"))
+        }
+      })?;
+    }
+    edges.push((variant, to));
+  }
+
+  if !declared {
+    return Ok(None);
+  }
+
+  let mut quoted_can = TokenStream::new();
+  let mut quoted_successors = TokenStream::new();
+  let mut dot_nodes = TokenStream::new();
+  let mut dot_edges = String::new();
+
+  for ((variant, to), title) in edges.iter().zip(titles.iter()) {
+    let matching_format = variant_matching_format(name, variant)?;
+    let variant_name = variant.ident.to_string();
+
+    let mut quoted_next = TokenStream::new();
+    let mut quoted_defaults = TokenStream::new();
+    for next in to.iter() {
+      let next_matching_format = variant_matching_format(name, next)?;
+      let next_default_format = variant_default_format(name, next)?;
+      quoted_next.extend(quote! { (#matching_format, #next_matching_format) => true, });
+      quoted_defaults.extend(quote! { #next_default_format, });
+      dot_edges.push_str(&format!("  {} -> {};\n", variant_name, next.ident));
+    }
+
+    quoted_can.extend(quoted_next);
+    quoted_successors.extend(quote! { #matching_format => vec![#quoted_defaults], });
+    dot_nodes.extend(quote! {
+      dot.push_str(&format!("  {} [label={:?}];\n", #variant_name, #title));
+    });
+  }
+
+  let head = format!("digraph {} {{\n", name);
+
+  Ok(Some(quote! {
+    impl #impl_generics ::seoul::Transition for #name #ty_generics #where_clause {
+      fn can_transition_to(&self, next: &Self) -> bool {
+        #[allow(unreachable_patterns)]
+        match (self, next) {
+          #quoted_can
+          _ => false
+        }
+      }
+      fn successors(&self) -> Vec<Self> {
+        match self {
+          #quoted_successors
+        }
+      }
+      fn transition_dot() -> String {
+        let mut dot = String::from(#head);
+        #dot_nodes
+        dot.push_str(#dot_edges);
+        dot.push_str("}\n");
+        dot
+      }
+    }
+  }))
+}
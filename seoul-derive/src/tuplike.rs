@@ -17,68 +17,441 @@ pub fn impl_tuplike_macro(ast: &DeriveInput) -> Result<TokenStream> {
   let (ref_impl_generics, _, ref_where_clause) = gen_clone.split_for_impl();
 
 
-  // impl Tuplike trait
-  gen.extend(quote! {
-
-    impl #impl_generics Tuplike for #name #ty_generics #where_clause {
-      
-    }
-  });
-
   match &ast.data {
     Data::Struct(data) => {
 
-      let Some((
+      let attrs = parse_struct_attrs(ast)?;
+
+      let Tokens {
         tuple_token,
         ref_tuple_token,
+        mut_tuple_token,
+        tuple_args,
+        unpack_token,
         build_token,
         into_token,
-        ref_into_token
-      )) = get_tokens(&data.fields) else {
-        return Err(Error::new(ast.span(), "Not for 0 fields data"))
-      };
+        ref_into_token,
+        mut_into_token,
+        tuple_types,
+        tuple_idents,
+        tuple_members,
+        tuple_items,
+        flatten,
+        flattened,
+        adapted,
+        ..
+      } = get_tokens(&data.fields, &attrs)?;
+      let StructAttrs { projections, array, columns, nesting, converts, row, encode, .. } = attrs;
 
-      let tuple_args = expanded_tuple_argument(&data.fields);
+      let (field_names, field_types) = field_tables(&tuple_members, &tuple_types);
+      let (field_names_token, field_types_token) = field_tables_token(&field_names, &field_types, &tuple_types, &flattened);
+
+      // `fn into(self) -> () { () }` of zero fields
+      let allow_unit = if tuple_types.is_empty() { quote! { #[allow(clippy::unused_unit)] } } else { quote! {} };
 
       gen.extend(quote! {
 
-        impl #impl_generics From<#tuple_token> for #name #ty_generics #where_clause {
-          fn from(#tuple_args: #tuple_token) -> Self {
+        // impl Tuplike trait
+        #allow_unit
+        impl #impl_generics Tuplike for #name #ty_generics #where_clause {
+          type Tuple = #tuple_token;
+          type RefTuple<'tpl> = #ref_tuple_token where Self: 'tpl;
+          type MutTuple<'tpl> = #mut_tuple_token where Self: 'tpl;
+
+          const FIELD_NAMES: &'static [&'static str] = #field_names_token;
+          const FIELD_TYPES: &'static [&'static str] = #field_types_token;
+
+          fn into_tuple(self) -> Self::Tuple {
+            #into_token
+          }
+
+          fn from_tuple(#tuple_args: Self::Tuple) -> Self {
+            #unpack_token
             Self #build_token
           }
+
+          fn as_tuple(&self) -> Self::RefTuple<'_> {
+            #ref_into_token
+          }
+
+          fn as_tuple_mut(&mut self) -> Self::MutTuple<'_> {
+            #mut_into_token
+          }
         }
+      });
 
-        impl #impl_generics Into<#tuple_token> for #name #ty_generics #where_clause {
-          fn into(self) -> #tuple_token {
-            #into_token
+      // a flattened tuple is a `TupleConcat` projection, which would overlap the blanket impls of `From`/`Into`;
+      // the conversions are left to the trait methods
+      if !flatten {
+        gen.extend(quote! {
+
+          impl #impl_generics From<#tuple_token> for #name #ty_generics #where_clause {
+            fn from(#tuple_args: #tuple_token) -> Self {
+              Self #build_token
+            }
+          }
+
+          #allow_unit
+          impl #impl_generics Into<#tuple_token> for #name #ty_generics #where_clause {
+            fn into(self) -> #tuple_token {
+              #into_token
+            }
+          }
+
+          #allow_unit
+          impl #ref_impl_generics Into<#ref_tuple_token> for &'tpl #name #ty_generics #ref_where_clause {
+            fn into(self) -> #ref_tuple_token {
+              #ref_into_token
+            }
+          }
+
+          #allow_unit
+          impl #ref_impl_generics Into<#mut_tuple_token> for &'tpl mut #name #ty_generics #ref_where_clause {
+            fn into(self) -> #mut_tuple_token {
+              #mut_into_token
+            }
           }
+        });
+      }
+
+      let vis = &ast.vis;
+      let mut inherent = TokenStream::new();
+
+      // homogeneous fields to and from `[T; N]`
+      if array {
+        if flatten || adapted {
+          return Err(Error::new(ast.span(), "`array` is not for `flatten` or `into`/`with` fields"))
         }
+        let Some(ty) = tuple_types.first() else {
+          return Err(Error::new(ast.span(), "`array` needs at least one field"))
+        };
+        let ty_str = ty.to_token_stream().to_string();
+        if let Some(other) = tuple_types.iter().find(|x| x.to_token_stream().to_string()!=ty_str) {
+          return Err(Error::new(other.span(), format!(
+            "`array` needs fields of one type, but `{}` differs from `{}`", other.to_token_stream(), ty_str
+          )))
+        }
+        let n = tuple_types.len();
 
-        impl #ref_impl_generics Into<#ref_tuple_token> for &'tpl #name #ty_generics #ref_where_clause {
-          fn into(self) -> #ref_tuple_token {
-            #ref_into_token
+        gen.extend(quote! {
+
+          impl #impl_generics From<[#ty; #n]> for #name #ty_generics #where_clause {
+            fn from(array: [#ty; #n]) -> Self {
+              let [#(#tuple_idents),*] = array;
+              Self #build_token
+            }
+          }
+
+          impl #impl_generics Into<[#ty; #n]> for #name #ty_generics #where_clause {
+            fn into(self) -> [#ty; #n] {
+              let #tuple_args = #into_token;
+              [#(#tuple_idents),*]
+            }
           }
+
+          impl #ref_impl_generics Into<[&'tpl #ty; #n]> for &'tpl #name #ty_generics #ref_where_clause {
+            fn into(self) -> [&'tpl #ty; #n] {
+              let #tuple_args = #ref_into_token;
+              [#(#tuple_idents),*]
+            }
+          }
+        });
+
+        inherent.extend(quote! {
+          #vis fn iter_fields(&self) -> impl Iterator<Item = &#ty> {
+            let array: [&#ty; #n] = self.into();
+            array.into_iter()
+          }
+        });
+      }
+
+      // named projections: `fn <name>(&self)` and `fn into_<name>(self)`
+      for (projection, members) in projections.iter() {
+        let into_fn = Ident::new(&format!("into_{}", projection), projection.span());
+        let mut tys = TokenStream::new();
+        let mut ref_tys = TokenStream::new();
+        let mut refs = TokenStream::new();
+        let mut values = TokenStream::new();
+
+        for member in members.iter() {
+          let ty = &data.fields.iter().nth(field_index(&data.fields, member)?).unwrap().ty;
+          tys.extend(quote! { #ty, });
+          ref_tys.extend(quote! { &#ty, });
+          refs.extend(quote! { &self . #member, });
+          values.extend(quote! { self . #member, });
         }
-      });
+
+        inherent.extend(quote! {
+          #vis fn #projection(&self) -> (#ref_tys) {
+            (#refs)
+          }
+
+          #vis fn #into_fn(self) -> (#tys) {
+            (#values)
+          }
+        });
+      }
+
+      if !inherent.is_empty() {
+        gen.extend(quote! {
+          impl #impl_generics #name #ty_generics #where_clause {
+            #inherent
+          }
+        });
+      }
+
+      // heterogeneous lists of the fields, in the tuple order
+      if cfg!(feature = "hlist") {
+        let ref_types = tuple_members.iter()
+          .map(|x| Ok(&data.fields.iter().nth(field_index(&data.fields, x)?).unwrap().ty))
+          .collect::<Result<Vec<_>>>()?;
+
+        let nil = quote! { ::seoul::HNil };
+        let hlist_ty = tuple_types.iter().rev().fold(nil.clone(), |acc, x| quote! { ::seoul::HCons<#x, #acc> });
+        let ref_hlist_ty = ref_types.iter().rev().fold(nil.clone(), |acc, x| quote! { ::seoul::HCons<&'tpl #x, #acc> });
+        let into_hlist = tuple_items.iter().rev().fold(nil.clone(), |acc, x| quote! { ::seoul::HCons { head: #x, tail: #acc } });
+        let ref_hlist = tuple_members.iter().rev().fold(nil.clone(), |acc, x| quote! { ::seoul::HCons { head: &self . #x, tail: #acc } });
+        let hlist_pattern = tuple_idents.iter().rev().fold(nil, |acc, x| quote! { ::seoul::HCons { head: #x, tail: #acc } });
+
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::IntoHList for #name #ty_generics #where_clause {
+            type HList = #hlist_ty;
+            type RefHList<'tpl> = #ref_hlist_ty where Self: 'tpl;
+
+            fn into_hlist(self) -> Self::HList {
+              #into_hlist
+            }
+
+            fn from_hlist(hlist: Self::HList) -> Self {
+              let #hlist_pattern = hlist;
+              Self #build_token
+            }
+
+            fn as_hlist(&self) -> Self::RefHList<'_> {
+              #ref_hlist
+            }
+          }
+        });
+      }
+
+      // delimited text rows
+      if row && cfg!(feature = "csv") {
+        if flatten || adapted {
+          return Err(Error::new(ast.span(), "`row` is not for `flatten` or `into`/`with` fields"))
+        }
+        let n = tuple_members.len();
+        let indices = 0..n;
+        // 1-based, as the lines
+        let cols = 1..=n;
+
+        let mut generics = ast.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for ty in tuple_types.iter() {
+          where_clause.predicates.push(syn::parse_quote! { #ty: ::std::fmt::Display + ::std::str::FromStr });
+          where_clause.predicates.push(syn::parse_quote! { <#ty as ::std::str::FromStr>::Err: ::std::fmt::Display });
+        }
+
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::TupleRow for #name #ty_generics #where_clause {
+
+            fn to_fields(&self) -> Vec<String> {
+              vec![#(::std::string::ToString::to_string(&self . #tuple_members)),*]
+            }
+
+            fn from_fields(fields: &[&str]) -> Result<Self, ::seoul::RowError> {
+              if fields.len()!=#n {
+                return Err(::seoul::RowError::length(#n, fields.len()))
+              }
+              #(
+                let #tuple_idents = fields[#indices].parse::<#tuple_types>()
+                  .map_err(|err| ::seoul::RowError::parse(#cols, #field_names, err))?;
+              )*
+              Ok(Self #build_token)
+            }
+          }
+        });
+      }
+
+      // compact binary format
+      if encode && cfg!(feature = "encode") {
+        if adapted {
+          return Err(Error::new(ast.span(), "`encode` is not for `into`/`with` fields"))
+        }
+        let (encode_where_clause, decode_where_clause) = encode_where_clauses(ast, &tuple_types);
+
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::Encode for #name #ty_generics #encode_where_clause {
+            fn encode(&self, out: &mut Vec<u8>) {
+              #(::seoul::Encode::encode(&self . #tuple_members, out);)*
+            }
+          }
+
+          impl #impl_generics ::seoul::Decode for #name #ty_generics #decode_where_clause {
+            fn decode<TplRead: ::std::io::Read>(decoder: &mut ::seoul::Decoder<TplRead>) -> Result<Self, ::seoul::DecodeError> {
+              #(let #tuple_idents = decoder.decode::<#tuple_types>()?;)*
+              Ok(Self #build_token)
+            }
+          }
+        });
+      }
+
+      // conversions to and from the structs of the same tuple shape
+      for other in converts.iter() {
+
+        // static assertion on the tuple shapes (generic structs rely on the type check of the conversions)
+        if ast.generics.params.is_empty() {
+          gen.extend(quote::quote_spanned! { other.span() =>
+            const _: fn() = || {
+              fn same_tuple<A: Tuplike, B: Tuplike<Tuple = <A as Tuplike>::Tuple>>() {}
+              same_tuple::<#name, #other>();
+            };
+          });
+        }
+
+        gen.extend(quote! {
+
+          impl #impl_generics From<#name #ty_generics> for #other #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+              <#other as Tuplike>::from_tuple(<#name #ty_generics as Tuplike>::into_tuple(value))
+            }
+          }
+
+          impl #impl_generics From<#other> for #name #ty_generics #where_clause {
+            fn from(value: #other) -> Self {
+              <#name #ty_generics as Tuplike>::from_tuple(<#other as Tuplike>::into_tuple(value))
+            }
+          }
+        });
+      }
+
+      // struct of arrays: `<Name>Columns`
+      if columns {
+        if flatten || adapted {
+          return Err(Error::new(ast.span(), "`columns` is not for `flatten` or `into`/`with` fields"))
+        }
+        let Some(first) = tuple_members.first() else {
+          return Err(Error::new(ast.span(), "`columns` needs at least one field"))
+        };
+        let columns_name = Ident::new(&format!("{}Columns", name), name.span());
+        let is_named = matches!(first, syn::Member::Named(_));
+
+        // members of the columns struct, in the tuple order
+        let cols = tuple_members.iter().enumerate()
+          .map(|(i, x)| if is_named { x.clone() } else { syn::Member::Unnamed(syn::Index::from(i)) })
+          .collect::<Vec<_>>();
+        let iters = tuple_idents.iter().map(|x| Ident::new(&format!("{}_iter", x), x.span())).collect::<Vec<_>>();
+        let item_refs = nesting.wrap(&cols.iter().map(|x| quote! { &self . #x [index] }).collect::<Vec<_>>());
+
+        let def = if is_named {
+          quote! { #vis struct #columns_name #impl_generics #where_clause { #(#vis #cols: Vec<#tuple_types>,)* } }
+        } else {
+          quote! { #vis struct #columns_name #impl_generics ( #(#vis Vec<#tuple_types>,)* ) #where_clause; }
+        };
+        let doc = format!("Columns of `{}`: a `Vec` for each field, in the tuple order", name);
+
+        gen.extend(quote! {
+
+          #[doc = #doc]
+          #[derive(Debug, Clone, PartialEq)]
+          #def
+
+          impl #impl_generics Default for #columns_name #ty_generics #where_clause {
+            fn default() -> Self {
+              Self { #(#cols: Vec::new(),)* }
+            }
+          }
+
+          impl #impl_generics #columns_name #ty_generics #where_clause {
+
+            #vis fn push(&mut self, row: #name #ty_generics) {
+              let #tuple_args = Tuplike::into_tuple(row);
+              #(self . #cols . push(#tuple_idents);)*
+            }
+
+            /// length of the shortest column
+            #vis fn len(&self) -> usize {
+              [#(self . #cols . len(),)*].into_iter().min().unwrap_or(0)
+            }
+
+            #vis fn is_empty(&self) -> bool {
+              self.len()==0
+            }
+
+            #vis fn get<'tpl>(&'tpl self, index: usize) -> Option<#ref_tuple_token> {
+              if index < self.len() {
+                Some(#item_refs)
+              } else {
+                None
+              }
+            }
+
+            #vis fn iter<'tpl>(&'tpl self) -> impl Iterator<Item = #ref_tuple_token> {
+              (0..self.len()).map(move |index| #item_refs)
+            }
+          }
+
+          impl #impl_generics From<Vec<#name #ty_generics>> for #columns_name #ty_generics #where_clause {
+            fn from(rows: Vec<#name #ty_generics>) -> Self {
+              let mut columns = Self::default();
+              for row in rows {
+                columns.push(row);
+              }
+              columns
+            }
+          }
+
+          impl #impl_generics Into<Vec<#name #ty_generics>> for #columns_name #ty_generics #where_clause {
+            fn into(self) -> Vec<#name #ty_generics> {
+              let mut rows = Vec::with_capacity(self.len());
+              #(let mut #iters = self . #cols . into_iter();)*
+              while let (#(Some(#tuple_idents),)*) = (#(#iters.next(),)*) {
+                rows.push(<#name #ty_generics as Tuplike>::from_tuple(#tuple_args));
+              }
+              rows
+            }
+          }
+        });
+      }
     },
 
     Data::Enum(data) => {
 
-      for variant in data.variants.iter() {
+      let encode = parse_enum_attrs(ast)?;
+      let mut encode_arms = TokenStream::new();
+      let mut decode_arms = TokenStream::new();
+      let mut encode_types: Vec<syn::Type> = Vec::new();
+
+      let vis = &ast.vis;
+      let mut inherent = TokenStream::new();
+      // (tuple shape, variant) of the variants with trait conversions
+      let mut shapes: Vec<(String, &Ident)> = Vec::new();
+
+      for (index, variant) in data.variants.iter().enumerate() {
         let variant_name = &variant.ident;
+        let skip = parse_variant_attrs(variant)?;
 
-        if let Some((
-          tuple_token,
-          _ref_tuple_token,
-          build_token,
-          _into_token,
-          _ref_into_token
-        )) = get_tokens(&variant.fields) {
+        let Tokens { tuple_token, ref_tuple_token, tuple_args, build_token, pattern_token, tuple_values, tuple_types, tuple_idents, tuple_members, flatten, adapted, .. } = get_tokens(&variant.fields, &StructAttrs::default())?;
+        if flatten {
+          return Err(Error::new(variant_name.span(), "`flatten` is only for struct fields"))
+        }
+        let snake = snake_case(&variant_name.to_string());
+
+        // variants of an identical tuple shape would conflict in trait impls
+        if !skip {
+          let shape = tuple_token.to_string();
+          if let Some((_, prev)) = shapes.iter().find(|(x, _)| x==&shape) {
+            return Err(Error::new(variant_name.span(), format!(
+              "Variant `{}` has the same tuple shape `{}` as variant `{}`, which makes conflicting `From`/`TryFrom` implementations. \
+              Add `#[tuplike(skip)]` to one of them and use `{}::from_{}_tuple` instead.",
+              variant_name, shape, prev, name, snake
+            )));
+          }
+          shapes.push((shape, variant_name));
+        }
 
-          let tuple_args = expanded_tuple_argument(&variant.fields);
-  
+        if !skip {
           gen.extend(quote! {
-    
+      
             impl #impl_generics From<#tuple_token> for #name #ty_generics #where_clause {
               fn from(#tuple_args: #tuple_token) -> Self {
                 Self :: #variant_name #build_token
@@ -86,7 +459,114 @@ pub fn impl_tuplike_macro(ast: &DeriveInput) -> Result<TokenStream> {
             }
           });
         }
-      }     
+
+        // reverse: TryFrom<Self> for the tuple, returning the original value on a mismatch
+        // (skipped when the tuple has generic parameters, by the orphan rule)
+        if !skip && !mentions_generics(&ast.generics, &tuple_token) {
+          gen.extend(quote! {
+
+            impl #impl_generics TryFrom<#name #ty_generics> for #tuple_token #where_clause {
+              type Error = #name #ty_generics;
+              fn try_from(value: #name #ty_generics) -> Result<Self, Self::Error> {
+                match value {
+                  #name :: #variant_name #pattern_token => Ok(#tuple_values),
+                  #[allow(unreachable_patterns)]
+                  value => Err(value)
+                }
+              }
+            }
+          });
+        }
+
+        // the variant index before the fields
+        if encode {
+          if adapted {
+            return Err(Error::new(variant_name.span(), "`encode` is not for `into`/`with` fields"))
+          }
+          encode_types.extend(tuple_types.iter().cloned());
+          let index = proc_macro2::Literal::u64_unsuffixed(index as u64);
+          encode_arms.extend(quote! {
+            Self :: #variant_name #pattern_token => {
+              ::seoul::write_varint(#index, out);
+              #(::seoul::Encode::encode(#tuple_idents, out);)*
+            },
+          });
+          decode_arms.extend(quote! {
+            #index => {
+              #(let #tuple_idents = decoder.decode::<#tuple_types>()?;)*
+              Ok(Self :: #variant_name #build_token)
+            },
+          });
+        }
+
+        // `<VARIANT>_FIELD_NAMES`, `<VARIANT>_FIELD_TYPES`
+        let (field_names, field_types) = field_tables(&tuple_members, &tuple_types);
+        let upper = snake.to_uppercase();
+        let names_const = Ident::new(&format!("{}_FIELD_NAMES", upper), variant_name.span());
+        let types_const = Ident::new(&format!("{}_FIELD_TYPES", upper), variant_name.span());
+
+        inherent.extend(quote! {
+          #vis const #names_const: &'static [&'static str] = &[#(#field_names),*];
+          #vis const #types_const: &'static [&'static str] = &[#(#field_types),*];
+        });
+
+        // `from_<variant>_tuple`, `into_<variant>_tuple`, `as_<variant>_tuple`
+        let from_fn = Ident::new(&format!("from_{}_tuple", snake), variant_name.span());
+        let into_fn = Ident::new(&format!("into_{}_tuple", snake), variant_name.span());
+        let as_fn = Ident::new(&format!("as_{}_tuple", snake), variant_name.span());
+
+        inherent.extend(quote! {
+          #vis fn #from_fn(#tuple_args: #tuple_token) -> Self {
+            Self :: #variant_name #build_token
+          }
+
+          #vis fn #into_fn(self) -> Option<#tuple_token> {
+            match self {
+              Self :: #variant_name #pattern_token => Some(#tuple_values),
+              #[allow(unreachable_patterns)]
+              _ => None
+            }
+          }
+
+          #vis fn #as_fn<'tpl>(&'tpl self) -> Option<#ref_tuple_token> {
+            match self {
+              Self :: #variant_name #pattern_token => Some(#tuple_args),
+              #[allow(unreachable_patterns)]
+              _ => None
+            }
+          }
+        });
+      }
+
+      gen.extend(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+          #inherent
+        }
+      });
+
+      if encode {
+        let (encode_where_clause, decode_where_clause) = encode_where_clauses(ast, &encode_types);
+
+        gen.extend(quote! {
+          impl #impl_generics ::seoul::Encode for #name #ty_generics #encode_where_clause {
+            fn encode(&self, out: &mut Vec<u8>) {
+              match self {
+                #encode_arms
+              }
+            }
+          }
+
+          impl #impl_generics ::seoul::Decode for #name #ty_generics #decode_where_clause {
+            fn decode<TplRead: ::std::io::Read>(decoder: &mut ::seoul::Decoder<TplRead>) -> Result<Self, ::seoul::DecodeError> {
+              let position = decoder.position();
+              match decoder.decode::<u64>()? {
+                #decode_arms
+                index => Err(::seoul::DecodeError::InvalidVariant { position, index }),
+              }
+            }
+          }
+        });
+      }
     },
 
     Data::Union(_) => {
@@ -98,76 +578,570 @@ pub fn impl_tuplike_macro(ast: &DeriveInput) -> Result<TokenStream> {
 }
 
 
-fn expanded_tuple_argument(fields: &Fields) -> TokenStream {
+/// top level `#[tuplike(..)]` attribute of struct
+#[derive(Default)]
+struct StructAttrs {
+  /// `order = [b, a]`: field order of the tuple formats
+  order: Option<Vec<syn::Member>>,
+  /// `projection(<name> = [a, b], ..)`: named subsets of the fields
+  projections: Vec<(Ident, Vec<syn::Member>)>,
+  /// `array`: to and from `[T; N]` of homogeneous fields
+  array: bool,
+  /// `chunks` or `cons`: nested shape of the tuple formats
+  nesting: Nesting,
+  /// `columns`: companion struct of arrays `<Name>Columns`
+  columns: bool,
+  /// `convert(Other, ..)`: conversions to and from the structs of the same tuple shape
+  converts: Vec<syn::Type>,
+  /// `row`: `TupleRow` of the feature `csv`
+  row: bool,
+  /// `encode`: `Encode`/`Decode` of the feature `encode`
+  encode: bool,
+}
+
+/// shape of the tuple formats
+#[derive(Default, Clone, Copy, PartialEq)]
+enum Nesting {
+  /// `(a, b, c)`
+  #[default]
+  Flat,
+  /// `((f0, .., f11), (f12, ..))`: chunks of 12 fields, within the std tuple trait impls
+  Chunks,
+  /// `(a, (b, (c, ())))`
+  Cons,
+}
+
+impl Nesting {
+
+  /// wrap the items of the tuple order into the shape
+  fn wrap(self, items: &[TokenStream]) -> TokenStream {
+    match self {
+      Self::Flat => quote! { (#(#items,)*) },
+      Self::Chunks => {
+        let chunks = items.chunks(12).map(|x| quote! { (#(#x,)*) });
+        quote! { (#(#chunks,)*) }
+      },
+      Self::Cons => items.iter().rev().fold(quote! { () }, |acc, x| quote! { (#x, #acc) }),
+    }
+  }
+}
+
+fn parse_struct_attrs(ast: &DeriveInput) -> Result<StructAttrs> {
+
+  let mut attrs = StructAttrs::default();
+
+  // `[a, b]` or `[0, 1]`
+  fn parse_members(input: syn::parse::ParseStream) -> Result<Vec<syn::Member>> {
+    let content;
+    syn::bracketed!(content in input);
+    let members = content.parse_terminated(<syn::Member as syn::parse::Parse>::parse, syn::Token![,])?;
+    Ok(members.into_iter().collect())
+  }
+
+  for attr in ast.attrs.iter().filter(|x| x.path().is_ident("tuplike")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("order") {
+        attrs.order = Some(parse_members(meta.value()?)?);
+        Ok(())
+      } else if meta.path.is_ident("array") {
+        attrs.array = true;
+        Ok(())
+      } else if meta.path.is_ident("columns") {
+        attrs.columns = true;
+        Ok(())
+      } else if meta.path.is_ident("row") {
+        attrs.row = true;
+        Ok(())
+      } else if meta.path.is_ident("encode") {
+        attrs.encode = true;
+        Ok(())
+      } else if meta.path.is_ident("convert") {
+        let content;
+        syn::parenthesized!(content in meta.input);
+        let types = content.parse_terminated(<syn::Type as syn::parse::Parse>::parse, syn::Token![,])?;
+        attrs.converts.extend(types);
+        Ok(())
+      } else if meta.path.is_ident("chunks") || meta.path.is_ident("cons") {
+        if attrs.nesting!=Nesting::Flat {
+          return Err(meta.error("Only one of `chunks` and `cons` is allowed"))
+        }
+        attrs.nesting = if meta.path.is_ident("chunks") { Nesting::Chunks } else { Nesting::Cons };
+        Ok(())
+      } else if meta.path.is_ident("projection") {
+        meta.parse_nested_meta(|meta| {
+          let Some(name) = meta.path.get_ident() else {
+            return Err(meta.error("Projection name should be an ident"))
+          };
+          if attrs.projections.iter().any(|(x, _)| x==name) {
+            return Err(meta.error(format!("Duplicate projection `{}`", name)))
+          }
+          attrs.projections.push((name.clone(), parse_members(meta.value()?)?));
+          Ok(())
+        })
+      } else {
+        Err(meta.error("Top level `tuplike` attribute has arguments of `order = [..]`, `projection(<name> = [..])`, `array`, `chunks`, `cons`, `columns`, `convert(..)`, `row` and `encode`"))
+      }
+    })?;
+  }
+  Ok(attrs)
+}
+
+
+/// index of the field named (or numbered) by the member
+fn field_index(fields: &Fields, member: &syn::Member) -> Result<usize> {
+  fields.iter().enumerate()
+    .position(|(i, field)| match (member, &field.ident) {
+      (syn::Member::Named(x), Some(ident)) => x==ident,
+      (syn::Member::Unnamed(x), None) => x.index as usize==i,
+      _ => false,
+    })
+    .ok_or_else(|| Error::new(member.span(), format!("No such field `{}`", member.to_token_stream())))
+}
+
+
+/// top level `#[tuplike(..)]` attribute of enum
+/// * return whether `encode` is given (with the feature `encode`)
+fn parse_enum_attrs(ast: &DeriveInput) -> Result<bool> {
+
+  let mut encode = false;
+
+  for attr in ast.attrs.iter().filter(|x| x.path().is_ident("tuplike")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("encode") {
+        encode = true;
+        Ok(())
+      } else {
+        Err(meta.error("Top level `tuplike` attribute of enum has an argument of `encode`"))
+      }
+    })?;
+  }
+  Ok(encode && cfg!(feature = "encode"))
+}
+
+
+/// where clauses of `Encode` and `Decode`, bounding each field type
+fn encode_where_clauses(ast: &DeriveInput, tys: &[syn::Type]) -> (syn::WhereClause, syn::WhereClause) {
+  let mut encode_generics = ast.generics.clone();
+  let mut decode_generics = ast.generics.clone();
+  let encode_where_clause = encode_generics.make_where_clause();
+  let decode_where_clause = decode_generics.make_where_clause();
+  for ty in tys.iter() {
+    encode_where_clause.predicates.push(syn::parse_quote! { #ty: ::seoul::Encode });
+    decode_where_clause.predicates.push(syn::parse_quote! { #ty: ::seoul::Decode });
+  }
+  (encode_where_clause.clone(), decode_where_clause.clone())
+}
+
+
+/// variant level `#[tuplike(..)]` attribute
+/// * return whether `skip` is given
+fn parse_variant_attrs(variant: &syn::Variant) -> Result<bool> {
+
+  let mut skip = false;
 
-  let mut token = TokenStream::new();
-  for i in 0..fields.len() {
-    let f = Ident::new(&format!("f{}", i), fields.span());
-    token.extend(quote! { #f , });
+  for attr in variant.attrs.iter().filter(|x| x.path().is_ident("tuplike")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("skip") {
+        skip = true;
+        Ok(())
+      } else {
+        Err(meta.error("Variant level `tuplike` attribute has an argument of `skip`"))
+      }
+    })?;
   }
-  quote! { (#token) }
+  Ok(skip)
+}
+
+
+/// whether the tokens (of field types) mention any type parameter of the generics
+fn mentions_generics(generics: &syn::Generics, tokens: &TokenStream) -> bool {
+
+  let params: Vec<String> = generics.type_params().map(|x| x.ident.to_string()).collect();
+
+  fn visit(tokens: TokenStream, params: &[String]) -> bool {
+    tokens.into_iter().any(|token| match token {
+      proc_macro2::TokenTree::Ident(ident) => params.contains(&ident.to_string()),
+      proc_macro2::TokenTree::Group(group) => visit(group.stream(), params),
+      _ => false,
+    })
+  }
+
+  visit(tokens.clone(), &params)
+}
+
+
+/// field level `#[tuplike(..)]` attribute
+#[derive(Default)]
+struct FieldAttrs {
+  /// `skip`: omitted from the tuple formats
+  skip: bool,
+  /// `default = expr`: value of a skipped field in building
+  default: Option<syn::Expr>,
+  /// `flatten`: the field's own `Tuplike` tuple is inlined
+  flatten: bool,
+  /// `into = T, with = module`: the tuple holds `T`, converted by `module::to` and `module::from`
+  adapter: Option<(syn::Type, syn::Path)>,
+}
+
+fn parse_field_attrs(field: &syn::Field) -> Result<FieldAttrs> {
+
+  let mut attrs = FieldAttrs::default();
+  let mut into: Option<syn::Type> = None;
+  let mut with: Option<syn::Path> = None;
+
+  for attr in field.attrs.iter().filter(|x| x.path().is_ident("tuplike")) {
+    attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident("skip") {
+        attrs.skip = true;
+        Ok(())
+      } else if meta.path.is_ident("default") {
+        attrs.default = Some(meta.value()?.parse()?);
+        Ok(())
+      } else if meta.path.is_ident("flatten") {
+        attrs.flatten = true;
+        Ok(())
+      } else if meta.path.is_ident("into") {
+        into = Some(meta.value()?.parse()?);
+        Ok(())
+      } else if meta.path.is_ident("with") {
+        with = Some(meta.value()?.parse()?);
+        Ok(())
+      } else {
+        Err(meta.error("Field level `tuplike` attribute has arguments of `skip`, `default = expr`, `flatten`, `into = T` and `with = module`"))
+      }
+    })?;
+  }
+
+  attrs.adapter = match (into, with) {
+    (Some(into), Some(with)) => Some((into, with)),
+    (None, None) => None,
+    _ => return Err(Error::new(field.span(), "`into = T` and `with = module` should be given together"))
+  };
+  if attrs.adapter.is_some() && (attrs.skip || attrs.flatten) {
+    return Err(Error::new(field.span(), "`into`/`with` is not for `skip` or `flatten` fields"))
+  }
+  if attrs.skip && attrs.flatten {
+    return Err(Error::new(field.span(), "A field can't be both `skip` and `flatten`"))
+  }
+  if attrs.default.is_some() && !attrs.skip {
+    return Err(Error::new(field.span(), "`default` is only for a `skip` field"))
+  }
+  Ok(attrs)
+}
+
+
+/// names (`0`, `1`, .. of tuple structs) and stringified types of the fields in the tuple order
+fn field_tables(members: &[syn::Member], types: &[syn::Type]) -> (Vec<String>, Vec<String>) {
+  let names = members.iter().map(|x| match x {
+    syn::Member::Named(x) => x.to_string(),
+    syn::Member::Unnamed(x) => x.index.to_string(),
+  }).collect();
+  let types = types.iter().map(|x| type_string(&x.to_token_stream().to_string())).collect();
+  (names, types)
+}
+
+/// initializers of `FIELD_NAMES` and `FIELD_TYPES`
+/// * a flattened field contributes its own `FIELD_NAMES`/`FIELD_TYPES`, concatenated in const evaluation
+fn field_tables_token(names: &[String], types: &[String], tys: &[syn::Type], flattened: &[bool]) -> (TokenStream, TokenStream) {
+  if !flattened.contains(&true) {
+    return (quote! { &[#(#names),*] }, quote! { &[#(#types),*] })
+  }
+
+  let mut name_parts: Vec<TokenStream> = Vec::new();
+  let mut type_parts: Vec<TokenStream> = Vec::new();
+  for (((name, type_str), ty), flattened) in names.iter().zip(types.iter()).zip(tys.iter()).zip(flattened.iter()) {
+    if *flattened {
+      name_parts.push(quote! { <#ty as Tuplike>::FIELD_NAMES });
+      type_parts.push(quote! { <#ty as Tuplike>::FIELD_TYPES });
+    } else {
+      name_parts.push(quote! { &[#name] });
+      type_parts.push(quote! { &[#type_str] });
+    }
+  }
+  let concat = |parts: Vec<TokenStream>| quote! {
+    {
+      let (fields, len) = const { &::seoul::concat_fields(&[#(#parts),*]) };
+      fields.split_at(*len).0
+    }
+  };
+  (concat(name_parts), concat(type_parts))
+}
+
+/// `Vec < (u8 , String) >` => `Vec<(u8, String)>`
+fn type_string(s: &str) -> String {
+  [(" < ", "<"), (" <", "<"), ("< ", "<"), (" >", ">"), (" :: ", "::"), (":: ", "::"), ("& ", "&"),
+    (" ,", ","), ("( ", "("), (" )", ")"), ("[ ", "["), (" ]", "]"), (" ;", ";")]
+    .iter()
+    .fold(s.to_string(), |acc, (from, to)| acc.replace(from, to))
+}
+
+
+/// tokens of tuple formats
+struct Tokens {
+  /// `(A, B)`
+  tuple_token: TokenStream,
+  /// `(&'tpl A, &'tpl B)`
+  ref_tuple_token: TokenStream,
+  /// `(&'tpl mut A, &'tpl mut B)`
+  mut_tuple_token: TokenStream,
+  /// `(f0, f1)`: argument of a tuple
+  tuple_args: TokenStream,
+  /// statements binding the fields from the tuple argument, before `build_token` (for `flatten`)
+  unpack_token: TokenStream,
+  /// `{ a: f0, b: f1 }` or `(f0, f1)`: build from the tuple argument
+  build_token: TokenStream,
+  /// `{ a: f0, b: f1, .. }` or `(f0, f1, _)`: pattern binding the tuple argument
+  pattern_token: TokenStream,
+  /// `(f0, f1)`: the tuple from the fields bound by `pattern_token`
+  tuple_values: TokenStream,
+  /// `[A, B]`: field types in the tuple order
+  tuple_types: Vec<syn::Type>,
+  /// `[f0, f1]`: idents of `tuple_args`
+  tuple_idents: Vec<Ident>,
+  /// `[a, b]` or `[0, 1]`: fields in the tuple order
+  tuple_members: Vec<syn::Member>,
+  /// `[self.a, self.b]`: values of the fields in the tuple order (before `flatten`)
+  tuple_items: Vec<TokenStream>,
+  /// whether any field is flattened
+  flatten: bool,
+  /// whether each field is flattened, in the tuple order
+  flattened: Vec<bool>,
+  /// whether any field has an `into`/`with` adapter
+  adapted: bool,
+  /// `(self.a, self.b)`
+  into_token: TokenStream,
+  /// `(&self.a, &self.b)`
+  ref_into_token: TokenStream,
+  /// `(&mut self.a, &mut self.b)`
+  mut_into_token: TokenStream,
 }
 
 
 /// for Struct fields
-/// * return (
-///   tuple-token,
-///   ref-tuple-token,
-///   build-token,
-///   into-token,
-///   ref-into-token,
-/// )
-/// * return None if length==0
-fn get_tokens(fields: &Fields) -> Option<(
-  TokenStream,
-  TokenStream, TokenStream,
-  TokenStream, TokenStream,
-)> {
-
-  let mut tuple_token: TokenStream = TokenStream::new();
-  let mut ref_tuple_token: TokenStream = TokenStream::new();
+/// * skipped fields are omitted from the tuple formats, and filled with their default in building
+/// * `order` rearranges the fields of the tuple formats, listing every field not skipped
+/// * `nesting` shapes the tuple formats
+/// * zero fields (and unit) make the tuple formats `()`
+fn get_tokens(fields: &Fields, struct_attrs: &StructAttrs) -> Result<Tokens> {
+
+  let mut tuple_token: Vec<TokenStream> = Vec::new();
+  let mut ref_tuple_token: Vec<TokenStream> = Vec::new();
+  let mut mut_tuple_token: Vec<TokenStream> = Vec::new();
+  let mut tuple_args: Vec<TokenStream> = Vec::new();
+  let mut tuple_values: Vec<TokenStream> = Vec::new();
   let mut build_token: TokenStream = TokenStream::new();
-  let mut into_token: TokenStream = TokenStream::new();
-  let mut ref_into_token: TokenStream = TokenStream::new();
+  let mut pattern_token: TokenStream = TokenStream::new();
+  let mut into_token: Vec<TokenStream> = Vec::new();
+  let mut ref_into_token: Vec<TokenStream> = Vec::new();
+  let mut mut_into_token: Vec<TokenStream> = Vec::new();
+  let mut tuple_types: Vec<syn::Type> = Vec::new();
+  let mut tuple_idents: Vec<Ident> = Vec::new();
+  let mut tuple_members: Vec<syn::Member> = Vec::new();
+  let mut tuple_items: Vec<TokenStream> = Vec::new();
+  let mut flattened: Vec<bool> = Vec::new();
+  let mut unpack_token: TokenStream = TokenStream::new();
 
   let len = fields.len();
-  if len==0 {
-    return None
+  let args = (0..len).map(|i| Ident::new(&format!("f{}", i), fields.span())).collect::<Vec<_>>();
+  let attrs = fields.iter().map(parse_field_attrs).collect::<Result<Vec<_>>>()?;
+
+  // indices of the fields in the tuple order
+  let indices: Vec<usize> = match &struct_attrs.order {
+    None => (0..len).filter(|i| !attrs[*i].skip).collect(),
+    Some(order) => {
+      let mut indices = Vec::new();
+      for member in order.iter() {
+        let i = field_index(fields, member)?;
+        if attrs[i].skip {
+          return Err(Error::new(member.span(), format!("Field `{}` is skipped", member.to_token_stream())))
+        }
+        if indices.contains(&i) {
+          return Err(Error::new(member.span(), format!("Duplicate field `{}`", member.to_token_stream())))
+        }
+        indices.push(i);
+      }
+      if let Some(i) = (0..len).find(|i| !attrs[*i].skip && !indices.contains(i)) {
+        let field = fields.iter().nth(i).unwrap();
+        let missing = field.ident.as_ref().map(|x| x.to_string()).unwrap_or(i.to_string());
+        return Err(Error::new(field.span(), format!("Field `{}` is missing in `order`", missing)))
+      }
+      indices
+    }
+  };
+
+  // building and pattern, in the field order
+  for ((field, attrs), f) in fields.iter().zip(attrs.iter()).zip(args.iter()) {
+
+    if attrs.skip {
+      let default = attrs.default.as_ref().map(|x| x.to_token_stream()).unwrap_or(quote! { Default::default() });
+      if let Some(name) = &field.ident {
+        build_token.extend(quote! { #name : #default, });
+      } else {
+        build_token.extend(quote! { #default, });
+        pattern_token.extend(quote! { _, });
+      }
+      continue;
+    }
+
+    let value = match &attrs.adapter {
+      Some((_, with)) => quote! { #with::from(#f) },
+      None => quote! { #f },
+    };
+    if let Some(name) = &field.ident {
+      build_token.extend(quote! { #name : #value, });
+      pattern_token.extend(quote! { #name : #f, });
+    } else {
+      build_token.extend(quote! { #value , });
+      pattern_token.extend(quote! { #f , });
+    }
   }
-  let is_named = if let Fields::Named(_) = fields { true } else { false };
 
-  for (i, field) in fields.iter().enumerate() {
+  // parts of the tuple formats to concatenate, for `flatten`:
+  // `<A as Tuplike>::Tuple` of a flattened field, `(B,)` of the other field.
+  let flatten = indices.iter().any(|i| attrs[*i].flatten);
+  let adapted = indices.iter().any(|i| attrs[*i].adapter.is_some());
+  let mut parts: Vec<[TokenStream; 6]> = Vec::new();
 
+  // tuple formats, in the tuple order
+  for i in indices {
+    let field = fields.iter().nth(i).unwrap();
+    let f = &args[i];
     let ty = field.ty.to_token_stream();
-    tuple_token.extend(quote! { #ty, });
-    ref_tuple_token.extend(quote! { &'tpl #ty, });
-    
-    let index = syn::Index::from(i);
-    let f = Ident::new(&format!("f{}", i), fields.span());
+    let member = match &field.ident {
+      Some(name) => syn::Member::Named(name.clone()),
+      None => syn::Member::Unnamed(syn::Index::from(i)),
+    };
 
-    if let Some(name) = &field.ident {
-      build_token.extend(quote! { #name : #f, });
-      into_token.extend(quote! { self . #name , });
-      ref_into_token.extend(quote! { &self . #name , });
+    // the tuple holds the adapted type; the ref tuples keep the field type
+    let (tuple_ty, into_value, value) = match &attrs[i].adapter {
+      Some((into, with)) => (
+        into.clone(),
+        quote! { #with::to(self . #member) },
+        quote! { #with::to(#f) },
+      ),
+      None => (field.ty.clone(), quote! { self . #member }, quote! { #f }),
+    };
+
+    tuple_token.push(quote! { #tuple_ty });
+    ref_tuple_token.push(quote! { &'tpl #ty });
+    mut_tuple_token.push(quote! { &'tpl mut #ty });
+    tuple_args.push(quote! { #f });
+    tuple_values.push(value);
+    tuple_types.push(tuple_ty.clone());
+    tuple_idents.push(f.clone());
+
+    tuple_members.push(member.clone());
+    tuple_items.push(into_value.clone());
+    flattened.push(attrs[i].flatten);
+    into_token.push(into_value.clone());
+    ref_into_token.push(quote! { &self . #member });
+    mut_into_token.push(quote! { &mut self . #member });
+
+    if attrs[i].flatten {
+      parts.push([
+        quote! { <#ty as Tuplike>::Tuple },
+        quote! { <#ty as Tuplike>::RefTuple<'tpl> },
+        quote! { <#ty as Tuplike>::MutTuple<'tpl> },
+        quote! { <#ty as Tuplike>::into_tuple(self . #member) },
+        quote! { <#ty as Tuplike>::as_tuple(&self . #member) },
+        quote! { <#ty as Tuplike>::as_tuple_mut(&mut self . #member) },
+      ]);
     } else {
-      build_token.extend(quote! { #f , });
-      into_token.extend(quote! { self . #index , });
-      ref_into_token.extend(quote! { &self . #index , });
+      parts.push([
+        quote! { (#tuple_ty,) },
+        quote! { (&'tpl #ty,) },
+        quote! { (&'tpl mut #ty,) },
+        quote! { (#into_value,) },
+        quote! { (&self . #member,) },
+        quote! { (&mut self . #member,) },
+      ]);
     }
   }
 
-  build_token = if is_named { quote! { { #build_token } } } else { quote! { (#build_token) } };
-  tuple_token = quote! { (#tuple_token) };
-  ref_tuple_token = quote! { (#ref_tuple_token) };
+  match fields {
+    Fields::Named(_) => {
+      build_token = quote! { { #build_token } };
+      pattern_token = quote! { { #pattern_token .. } };
+    },
+    Fields::Unnamed(_) => {
+      build_token = quote! { (#build_token) };
+      pattern_token = quote! { (#pattern_token) };
+    },
+    Fields::Unit => {}
+  }
+
+  let nesting = struct_attrs.nesting;
+  let mut tuple_token = nesting.wrap(&tuple_token);
+  let mut ref_tuple_token = nesting.wrap(&ref_tuple_token);
+  let mut mut_tuple_token = nesting.wrap(&mut_tuple_token);
+  let mut tuple_args = nesting.wrap(&tuple_args);
+  let tuple_values = nesting.wrap(&tuple_values);
+
+  let mut into_token = nesting.wrap(&into_token);
+  let mut ref_into_token = nesting.wrap(&ref_into_token);
+  let mut mut_into_token = nesting.wrap(&mut_into_token);
+
+  // fold the parts with `TupleConcat` from the left, and split them back from the right
+  if flatten {
+    if nesting!=Nesting::Flat {
+      return Err(Error::new(fields.span(), "`chunks` and `cons` are not for `flatten` fields"))
+    }
+    let mut acc = parts[0].clone();
+    // tuple types folded up to each part
+    let mut acc_tys = vec![acc[0].clone()];
+
+    for part in parts.iter().skip(1) {
+      let [ty, ref_ty, mut_ty, into, ref_into, mut_into] = part;
+      let [acc_ty, acc_ref_ty, acc_mut_ty, acc_into, acc_ref_into, acc_mut_into] = &acc;
+      acc = [
+        quote! { <#acc_ty as ::seoul::TupleConcat<#ty>>::Output },
+        quote! { <#acc_ref_ty as ::seoul::TupleConcat<#ref_ty>>::Output },
+        quote! { <#acc_mut_ty as ::seoul::TupleConcat<#mut_ty>>::Output },
+        quote! { ::seoul::TupleConcat::concat(#acc_into, #into) },
+        quote! { ::seoul::TupleConcat::concat(#acc_ref_into, #ref_into) },
+        quote! { ::seoul::TupleConcat::concat(#acc_mut_into, #mut_into) },
+      ];
+      acc_tys.push(acc[0].clone());
+    }
+    [tuple_token, ref_tuple_token, mut_tuple_token, into_token, ref_into_token, mut_into_token] = acc;
+
+    unpack_token.extend(quote! { let rest = tuple; });
+    for k in (1..parts.len()).rev() {
+      let (acc_ty, ty, f) = (&acc_tys[k-1], &parts[k][0], &tuple_idents[k]);
+      unpack_token.extend(quote! { let (rest, #f) = <#acc_ty as ::seoul::TupleConcat<#ty>>::split(rest); });
+    }
+    let f = &tuple_idents[0];
+    unpack_token.extend(quote! { let #f = rest; });
 
-  into_token = quote! { (#into_token) };
-  ref_into_token = quote! { (#ref_into_token) };
+    for (f, ty) in tuple_idents.iter().zip(tuple_types.iter()) {
+      let i = args.iter().position(|x| x==f).unwrap();
+      if attrs[i].flatten {
+        unpack_token.extend(quote! { let #f = <#ty as Tuplike>::from_tuple(#f); });
+      } else {
+        unpack_token.extend(quote! { let (#f,) = #f; });
+      }
+    }
+    tuple_args = quote! { tuple };
+  }
 
-  Some((
+  Ok(Tokens {
     tuple_token,
     ref_tuple_token,
+    mut_tuple_token,
+    tuple_args,
+    unpack_token,
     build_token,
+    pattern_token,
+    tuple_values,
+    tuple_types,
+    tuple_idents,
+    tuple_members,
+    tuple_items,
+    flatten,
+    flattened,
+    adapted,
     into_token,
-    ref_into_token
-  ))
-}
\ No newline at end of file
+    ref_into_token,
+    mut_into_token,
+  })
+}
@@ -0,0 +1,524 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// # Trait Encode, Decode
+///
+/// * Enabled with the feature `encode`.
+/// * A compact, deterministic binary format without dependencies:
+///   * `u8`, `i8` as a byte, `bool` as `0`/`1`; `f32`, `f64` as little endian bytes,
+///   * the other integers and `char` as LEB128 varints (zigzag for signed ones),
+///   * `String`, `Vec`, `VecDeque`, `BTreeMap`, `BTreeSet` with a varint length prefix,
+///     `Option` with a `0`/`1` byte, arrays and tuples element by element.
+/// * The `Tuplike` derive macro implements both with the top level `#[tuplike(encode)]` attribute:
+///   * struct fields in the tuple order (skipped fields are left out, and filled with their default in decoding),
+///   * enums with the varint index of the variant before its fields.
+///   * Not for `into`/`with` fields.
+/// * `Decoder` reads values one by one from a `Read`; input ending inside a value is a `DecodeError::Truncated`.
+///
+/// # Ex
+/// ```
+/// use seoul::{Tuplike, Encode, Decode, Decoder, DecodeError};
+///
+/// #[derive(Debug, Clone, PartialEq, Tuplike)]
+/// #[tuplike(encode)]
+/// struct Entry {
+///   id: u64, name: String, tags: Vec<String>
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Tuplike)]
+/// #[tuplike(encode)]
+/// enum Op {
+///   Put(Entry),
+///   Delete { id: u64 },
+///   Clear,
+/// }
+///
+/// let entry = Entry { id: 300, name: "a".to_string(), tags: vec![] };
+/// let bytes = entry.to_bytes();
+/// assert_eq!(bytes, vec![0xac, 0x02, 1, b'a', 0]);
+/// assert_eq!(Entry::from_bytes(&bytes).unwrap(), entry);
+///
+/// // streaming
+/// let mut bytes = Op::Put(entry.clone()).to_bytes();
+/// bytes.extend(Op::Delete { id: 1 }.to_bytes());
+/// bytes.extend(Op::Clear.to_bytes());
+/// let ops = Decoder::new(bytes.as_slice()).iter::<Op>().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(ops, vec![Op::Put(entry), Op::Delete { id: 1 }, Op::Clear]);
+///
+/// // truncated input
+/// let err = Op::from_bytes(&bytes[..3]).unwrap_err();
+/// assert!(matches!(err, DecodeError::Truncated { position: 3 }));
+/// ```
+pub trait Encode {
+
+  fn encode(&self, out: &mut Vec<u8>);
+
+  fn to_bytes(&self) -> Vec<u8> {
+    let mut out = Vec::new();
+    self.encode(&mut out);
+    out
+  }
+}
+
+pub trait Decode: Sized {
+
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError>;
+
+  /// decode a whole input of one value
+  fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    let value = Self::decode(&mut decoder)?;
+    if !decoder.is_end()? {
+      return Err(DecodeError::Trailing { position: decoder.position() })
+    }
+    Ok(value)
+  }
+}
+
+
+/// An error of decoding, at a byte position of the input
+#[derive(Debug)]
+pub enum DecodeError {
+  /// the input ends inside a value
+  Truncated { position: usize },
+  Io(io::Error),
+  /// a varint too large for the type
+  Overflow { position: usize },
+  /// bytes not of a value of the type, like `2` for `bool` or invalid UTF-8
+  InvalidValue { position: usize, type_name: &'static str },
+  /// an index of no variant
+  InvalidVariant { position: usize, index: u64 },
+  /// bytes left after a value of `Decode::from_bytes`
+  Trailing { position: usize },
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Truncated { position } => write!(f, "truncated input at byte {}", position),
+      Self::Io(err) => write!(f, "{}", err),
+      Self::Overflow { position } => write!(f, "varint overflow at byte {}", position),
+      Self::InvalidValue { position, type_name } => write!(f, "invalid `{}` at byte {}", type_name, position),
+      Self::InvalidVariant { position, index } => write!(f, "invalid variant index {} at byte {}", index, position),
+      Self::Trailing { position } => write!(f, "trailing bytes at byte {}", position),
+    }
+  }
+}
+
+impl std::error::Error for DecodeError {}
+
+
+/// Streaming decoder over a `Read`
+pub struct Decoder<R> {
+  reader: R,
+  /// bytes consumed
+  position: usize,
+  peeked: Option<u8>,
+}
+
+impl<R: Read> Decoder<R> {
+
+  pub fn new(reader: R) -> Self {
+    Self { reader, position: 0, peeked: None }
+  }
+
+  /// bytes consumed so far
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  pub fn into_inner(self) -> R {
+    self.reader
+  }
+
+  pub fn decode<T: Decode>(&mut self) -> Result<T, DecodeError> {
+    T::decode(self)
+  }
+
+  /// decode values until the input ends at a value boundary
+  pub fn iter<T: Decode>(self) -> DecodeIter<R, T> {
+    DecodeIter { decoder: self, _marker: std::marker::PhantomData }
+  }
+
+  /// whether the input has ended
+  pub fn is_end(&mut self) -> Result<bool, DecodeError> {
+    if self.peeked.is_some() {
+      return Ok(false)
+    }
+    let mut buf = [0u8];
+    loop {
+      match self.reader.read(&mut buf) {
+        Ok(0) => return Ok(true),
+        Ok(_) => {
+          self.peeked = Some(buf[0]);
+          return Ok(false)
+        },
+        Err(err) if err.kind()==io::ErrorKind::Interrupted => continue,
+        Err(err) => return Err(DecodeError::Io(err)),
+      }
+    }
+  }
+
+  pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+    if self.is_end()? {
+      return Err(DecodeError::Truncated { position: self.position })
+    }
+    self.position += 1;
+    Ok(self.peeked.take().unwrap())
+  }
+
+  /// read `len` bytes, without allocating more than the input holds
+  pub fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, DecodeError> {
+    let start = self.position;
+    let mut buf = Vec::new();
+    if len > 0 {
+      buf.push(self.read_u8()?);
+    }
+    let rest = (len - buf.len()) as u64;
+    (&mut self.reader).take(rest).read_to_end(&mut buf).map_err(DecodeError::Io)?;
+    self.position = start + buf.len();
+    if buf.len() < len {
+      return Err(DecodeError::Truncated { position: self.position })
+    }
+    Ok(buf)
+  }
+
+  /// LEB128 varint
+  pub fn read_varint(&mut self) -> Result<u128, DecodeError> {
+    let start = self.position;
+    let mut value: u128 = 0;
+    let mut shift = 0;
+    loop {
+      let byte = self.read_u8()?;
+      let bits = (byte & 0x7f) as u128;
+      if shift >= 128 || (shift > 121 && bits >> (128 - shift) != 0) {
+        return Err(DecodeError::Overflow { position: start })
+      }
+      value |= bits << shift;
+      if byte & 0x80 == 0 {
+        return Ok(value)
+      }
+      shift += 7;
+    }
+  }
+
+  /// varint length prefix
+  pub fn read_len(&mut self) -> Result<usize, DecodeError> {
+    let position = self.position;
+    usize::try_from(self.read_varint()?).map_err(|_| DecodeError::Overflow { position })
+  }
+}
+
+
+/// Iterator of `Decoder::iter`
+pub struct DecodeIter<R, T> {
+  decoder: Decoder<R>,
+  _marker: std::marker::PhantomData<T>,
+}
+
+impl<R: Read, T: Decode> Iterator for DecodeIter<R, T> {
+  type Item = Result<T, DecodeError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.decoder.is_end() {
+      Ok(true) => None,
+      Ok(false) => Some(self.decoder.decode()),
+      Err(err) => Some(Err(err)),
+    }
+  }
+}
+
+
+/// append a LEB128 varint
+pub fn write_varint(mut value: u128, out: &mut Vec<u8>) {
+  loop {
+    let byte = (value & 0x7f) as u8;
+    value >>= 7;
+    if value==0 {
+      out.push(byte);
+      return
+    }
+    out.push(byte | 0x80);
+  }
+}
+
+
+impl Encode for u8 {
+  fn encode(&self, out: &mut Vec<u8>) {
+    out.push(*self);
+  }
+}
+
+impl Decode for u8 {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    decoder.read_u8()
+  }
+}
+
+impl Encode for i8 {
+  fn encode(&self, out: &mut Vec<u8>) {
+    out.push(*self as u8);
+  }
+}
+
+impl Decode for i8 {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    Ok(decoder.read_u8()? as i8)
+  }
+}
+
+macro_rules! impl_encode_unsigned {
+  ($($ty:ty),*) => {
+    $(
+      impl Encode for $ty {
+        fn encode(&self, out: &mut Vec<u8>) {
+          write_varint(*self as u128, out);
+        }
+      }
+
+      impl Decode for $ty {
+        fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+          let position = decoder.position();
+          <$ty>::try_from(decoder.read_varint()?).map_err(|_| DecodeError::Overflow { position })
+        }
+      }
+    )*
+  };
+}
+
+impl_encode_unsigned!(u16, u32, u64, u128, usize);
+
+macro_rules! impl_encode_signed {
+  ($($ty:ty),*) => {
+    $(
+      impl Encode for $ty {
+        fn encode(&self, out: &mut Vec<u8>) {
+          let value = *self as i128;
+          write_varint(((value << 1) ^ (value >> 127)) as u128, out);
+        }
+      }
+
+      impl Decode for $ty {
+        fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+          let position = decoder.position();
+          let value = decoder.read_varint()?;
+          let value = (value >> 1) as i128 ^ -((value & 1) as i128);
+          <$ty>::try_from(value).map_err(|_| DecodeError::Overflow { position })
+        }
+      }
+    )*
+  };
+}
+
+impl_encode_signed!(i16, i32, i64, i128, isize);
+
+macro_rules! impl_encode_float {
+  ($($ty:ty),*) => {
+    $(
+      impl Encode for $ty {
+        fn encode(&self, out: &mut Vec<u8>) {
+          out.extend(self.to_le_bytes());
+        }
+      }
+
+      impl Decode for $ty {
+        fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+          let bytes = decoder.read_bytes(std::mem::size_of::<$ty>())?;
+          Ok(<$ty>::from_le_bytes(bytes.try_into().unwrap()))
+        }
+      }
+    )*
+  };
+}
+
+impl_encode_float!(f32, f64);
+
+impl Encode for bool {
+  fn encode(&self, out: &mut Vec<u8>) {
+    out.push(*self as u8);
+  }
+}
+
+impl Decode for bool {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    let position = decoder.position();
+    match decoder.read_u8()? {
+      0 => Ok(false),
+      1 => Ok(true),
+      _ => Err(DecodeError::InvalidValue { position, type_name: "bool" }),
+    }
+  }
+}
+
+impl Encode for char {
+  fn encode(&self, out: &mut Vec<u8>) {
+    (*self as u32).encode(out);
+  }
+}
+
+impl Decode for char {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    let position = decoder.position();
+    char::from_u32(decoder.decode()?).ok_or(DecodeError::InvalidValue { position, type_name: "char" })
+  }
+}
+
+impl Encode for str {
+  fn encode(&self, out: &mut Vec<u8>) {
+    self.len().encode(out);
+    out.extend(self.as_bytes());
+  }
+}
+
+impl Encode for String {
+  fn encode(&self, out: &mut Vec<u8>) {
+    self.as_str().encode(out);
+  }
+}
+
+impl Decode for String {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    let len = decoder.read_len()?;
+    let position = decoder.position();
+    String::from_utf8(decoder.read_bytes(len)?).map_err(|_| DecodeError::InvalidValue { position, type_name: "String" })
+  }
+}
+
+impl<T: Encode + ?Sized> Encode for &T {
+  fn encode(&self, out: &mut Vec<u8>) {
+    (**self).encode(out);
+  }
+}
+
+impl<T: Encode + ?Sized> Encode for Box<T> {
+  fn encode(&self, out: &mut Vec<u8>) {
+    (**self).encode(out);
+  }
+}
+
+impl<T: Decode> Decode for Box<T> {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    Ok(Box::new(decoder.decode()?))
+  }
+}
+
+impl<T: Encode> Encode for Option<T> {
+  fn encode(&self, out: &mut Vec<u8>) {
+    match self {
+      None => out.push(0),
+      Some(x) => {
+        out.push(1);
+        x.encode(out);
+      }
+    }
+  }
+}
+
+impl<T: Decode> Decode for Option<T> {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    let position = decoder.position();
+    match decoder.read_u8()? {
+      0 => Ok(None),
+      1 => Ok(Some(decoder.decode()?)),
+      _ => Err(DecodeError::InvalidValue { position, type_name: "Option" }),
+    }
+  }
+}
+
+impl<T: Encode> Encode for [T] {
+  fn encode(&self, out: &mut Vec<u8>) {
+    self.len().encode(out);
+    for x in self.iter() {
+      x.encode(out);
+    }
+  }
+}
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+  fn encode(&self, out: &mut Vec<u8>) {
+    for x in self.iter() {
+      x.encode(out);
+    }
+  }
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+  fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+    let mut items = Vec::with_capacity(N);
+    for _ in 0..N {
+      items.push(decoder.decode()?);
+    }
+    Ok(items.try_into().unwrap_or_else(|_| unreachable!()))
+  }
+}
+
+macro_rules! impl_encode_collection {
+  ($($ty:ident<$($param:ident),*> $(where $($bound:ident: $trait:ident),*)? => $push:expr),*) => {
+    $(
+      impl<$($param: Encode),*> Encode for $ty<$($param),*> {
+        fn encode(&self, out: &mut Vec<u8>) {
+          self.len().encode(out);
+          for x in self.iter() {
+            x.encode(out);
+          }
+        }
+      }
+
+      impl<$($param: Decode),*> Decode for $ty<$($param),*> $(where $($bound: $trait),*)? {
+        fn decode<R: Read>(decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+          let len = decoder.read_len()?;
+          let mut items = $ty::new();
+          for _ in 0..len {
+            $push(&mut items, decoder.decode()?);
+          }
+          Ok(items)
+        }
+      }
+    )*
+  };
+}
+
+impl_encode_collection!(
+  Vec<T> => Vec::push,
+  VecDeque<T> => VecDeque::push_back,
+  BTreeSet<T> where T: Ord => |items: &mut BTreeSet<T>, x| { items.insert(x); },
+  BTreeMap<K, V> where K: Ord => |items: &mut BTreeMap<K, V>, (k, v): (K, V)| { items.insert(k, v); }
+);
+
+macro_rules! impl_encode_tuple {
+  ($(($($name:ident),*)),*) => {
+    $(
+      #[allow(non_snake_case, clippy::unused_unit)]
+      impl<$($name: Encode),*> Encode for ($($name,)*) {
+        fn encode(&self, _out: &mut Vec<u8>) {
+          let ($($name,)*) = self;
+          $($name.encode(_out);)*
+        }
+      }
+
+      #[allow(non_snake_case, clippy::unused_unit)]
+      impl<$($name: Decode),*> Decode for ($($name,)*) {
+        fn decode<R: Read>(_decoder: &mut Decoder<R>) -> Result<Self, DecodeError> {
+          Ok(($(_decoder.decode::<$name>()?,)*))
+        }
+      }
+    )*
+  };
+}
+
+impl_encode_tuple!(
+  (),
+  (A0),
+  (A0, A1),
+  (A0, A1, A2),
+  (A0, A1, A2, A3),
+  (A0, A1, A2, A3, A4),
+  (A0, A1, A2, A3, A4, A5),
+  (A0, A1, A2, A3, A4, A5, A6),
+  (A0, A1, A2, A3, A4, A5, A6, A7),
+  (A0, A1, A2, A3, A4, A5, A6, A7, A8),
+  (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9),
+  (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10),
+  (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11)
+);
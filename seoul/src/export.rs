@@ -0,0 +1,278 @@
+use crate::Isomorphism;
+
+/// # Trait IsomorphismExport
+///
+/// * Enabled with the feature `export`.
+/// * The `Isomorphism` derive macro implements this trait along with `Isomorphism`.
+/// * Render the enum's full mapping table (variant, title, each `into` value, description)
+///   as JSON, CSV or a TypeScript declaration.
+///
+/// # Ex
+/// ```
+/// # use seoul::{Isomorphism, IsomorphismExport};
+///
+/// #[derive(PartialEq, Debug, Isomorphism)]
+/// #[isomorphism(u8)]
+/// enum ABC {
+///   #[into(0)] #[title("a")] #[description("first one")] A,
+///   #[into(1)] B,
+/// }
+///
+/// assert_eq!(ABC::export_csv(), "variant,title,u8,description\nA,a,0,first one\nB,B,1,\n");
+/// assert_eq!(
+///   ABC::export_json(),
+///   r#"[{"variant":"A","title":"a","u8":0,"description":"first one"},{"variant":"B","title":"B","u8":1,"description":null}]"#
+/// );
+/// assert!(ABC::export_typescript().starts_with("export enum ABC {\n  A = \"a\",\n  B = \"B\",\n}\n"));
+/// ```
+pub trait IsomorphismExport: Isomorphism {
+
+  fn mapping_table() -> MappingTable;
+
+  fn export_json() -> String {
+    Self::mapping_table().to_json()
+  }
+
+  fn export_csv() -> String {
+    Self::mapping_table().to_csv()
+  }
+
+  fn export_typescript() -> String {
+    Self::mapping_table().to_typescript()
+  }
+}
+
+
+/// A mapping table of an enum: one row per variant, one value column per `into` type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingTable {
+  /// name of the enum
+  pub name: &'static str,
+  /// `into` type names, in the order of the top level attribute
+  pub columns: Vec<&'static str>,
+  pub rows: Vec<MappingRow>,
+}
+
+/// A row of `MappingTable`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingRow {
+  /// name of the variant
+  pub variant: &'static str,
+  pub title: String,
+  /// `into` values, in the order of `MappingTable::columns`
+  pub values: Vec<ExportValue>,
+  pub description: Option<String>,
+}
+
+/// A rendered `into` value
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportValue {
+  Null,
+  Bool(bool),
+  Number(String),
+  Str(String),
+}
+
+/// Convert an `into` value into `ExportValue`
+pub trait ToExportValue {
+  fn to_export_value(&self) -> ExportValue;
+}
+
+
+impl MappingTable {
+
+  fn headers(&self) -> Vec<&'static str> {
+    let mut headers = vec!["variant", "title"];
+    headers.extend(self.columns.iter());
+    headers.push("description");
+    headers
+  }
+
+  /// `[{"variant": .., "title": .., <into type>: .., "description": ..}, ..]`
+  pub fn to_json(&self) -> String {
+    let headers = self.headers();
+    let mut out = String::from("[");
+
+    for (i, row) in self.rows.iter().enumerate() {
+      if i > 0 { out.push(','); }
+
+      let mut cells = vec![
+        json_string(row.variant),
+        json_string(&row.title),
+      ];
+      cells.extend(row.values.iter().map(|x| x.to_json()));
+      cells.push(row.description.as_deref().map(json_string).unwrap_or_else(|| "null".to_string()));
+
+      out.push('{');
+      for (j, (header, cell)) in headers.iter().zip(cells.iter()).enumerate() {
+        if j > 0 { out.push(','); }
+        out.push_str(&json_string(header));
+        out.push(':');
+        out.push_str(cell);
+      }
+      out.push('}');
+    }
+    out.push(']');
+    out
+  }
+
+  /// header line of `variant,title,<into types..>,description` and a line for each row
+  pub fn to_csv(&self) -> String {
+    let mut out = self.headers().iter().map(|x| csv_field(x)).collect::<Vec<_>>().join(",");
+    out.push('\n');
+
+    for row in self.rows.iter() {
+      let mut cells = vec![csv_field(row.variant), csv_field(&row.title)];
+      cells.extend(row.values.iter().map(|x| x.to_csv()));
+      cells.push(row.description.as_deref().map(csv_field).unwrap_or_default());
+      out.push_str(&cells.join(","));
+      out.push('\n');
+    }
+    out
+  }
+
+  /// * `export enum <Name>` of variant to title,
+  /// * `export type <Name>Title` union of titles,
+  /// * `export const <Name>Table` of every row.
+  pub fn to_typescript(&self) -> String {
+    let name = self.name;
+    let mut out = format!("export enum {} {{\n", name);
+    for row in self.rows.iter() {
+      out.push_str(&format!("  {} = {},\n", row.variant, json_string(&row.title)));
+    }
+    out.push_str("}\n\n");
+
+    let titles = self.rows.iter().map(|x| json_string(&x.title)).collect::<Vec<_>>();
+    let titles = if titles.is_empty() { "never".to_string() } else { titles.join(" | ") };
+    out.push_str(&format!("export type {}Title = {};\n\n", name, titles));
+
+    out.push_str(&format!("export const {}Table = {{\n", name));
+    for row in self.rows.iter() {
+      let mut cells = vec![format!("title: {}", json_string(&row.title))];
+      for (column, value) in self.columns.iter().zip(row.values.iter()) {
+        cells.push(format!("{}: {}", json_string(column), value.to_json()));
+      }
+      cells.push(format!("description: {}", row.description.as_deref().map(json_string).unwrap_or_else(|| "null".to_string())));
+      out.push_str(&format!("  {}: {{ {} }},\n", row.variant, cells.join(", ")));
+    }
+    out.push_str("} as const;\n");
+    out
+  }
+}
+
+
+impl ExportValue {
+
+  pub fn to_json(&self) -> String {
+    match self {
+      Self::Null => "null".to_string(),
+      Self::Bool(x) => x.to_string(),
+      Self::Number(x) => x.clone(),
+      Self::Str(x) => json_string(x),
+    }
+  }
+
+  pub fn to_csv(&self) -> String {
+    match self {
+      Self::Null => String::new(),
+      Self::Bool(x) => x.to_string(),
+      Self::Number(x) => x.clone(),
+      Self::Str(x) => csv_field(x),
+    }
+  }
+}
+
+
+fn json_string(s: &str) -> String {
+  let mut out = String::with_capacity(s.len()+2);
+  out.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+  out
+}
+
+fn csv_field(s: &str) -> String {
+  if s.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", s.replace('"', "\"\""))
+  } else {
+    s.to_string()
+  }
+}
+
+
+macro_rules! impl_to_export_value_int {
+  ($($ty:ty),*) => {
+    $(
+      impl ToExportValue for $ty {
+        fn to_export_value(&self) -> ExportValue {
+          ExportValue::Number(self.to_string())
+        }
+      }
+    )*
+  };
+}
+
+impl_to_export_value_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_to_export_value_float {
+  ($($ty:ty),*) => {
+    $(
+      impl ToExportValue for $ty {
+        fn to_export_value(&self) -> ExportValue {
+          if self.is_finite() { ExportValue::Number(self.to_string()) } else { ExportValue::Null }
+        }
+      }
+    )*
+  };
+}
+
+impl_to_export_value_float!(f32, f64);
+
+impl ToExportValue for bool {
+  fn to_export_value(&self) -> ExportValue {
+    ExportValue::Bool(*self)
+  }
+}
+
+impl ToExportValue for char {
+  fn to_export_value(&self) -> ExportValue {
+    ExportValue::Str(self.to_string())
+  }
+}
+
+impl ToExportValue for str {
+  fn to_export_value(&self) -> ExportValue {
+    ExportValue::Str(self.to_string())
+  }
+}
+
+impl ToExportValue for String {
+  fn to_export_value(&self) -> ExportValue {
+    ExportValue::Str(self.clone())
+  }
+}
+
+impl<T: ToExportValue + ?Sized> ToExportValue for &T {
+  fn to_export_value(&self) -> ExportValue {
+    (**self).to_export_value()
+  }
+}
+
+impl<T: ToExportValue> ToExportValue for Option<T> {
+  fn to_export_value(&self) -> ExportValue {
+    match self {
+      Some(x) => x.to_export_value(),
+      None => ExportValue::Null,
+    }
+  }
+}
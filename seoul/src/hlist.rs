@@ -0,0 +1,154 @@
+/// # Trait HList
+///
+/// * Enabled with the feature `hlist`.
+/// * Heterogeneous list: `HCons<H, T>` of a head and a tail, ended with `HNil`: `HCons<A, HCons<B, HNil>>` for `(A, B)`.
+/// * Unlike tuples, it can be recursed over generically:
+///   `HMap` and `HFold` apply a polymorphic function (`Func`, implemented for each element type) to every element.
+/// * The `Tuplike` derive macro implements `IntoHList` for structs, in the tuple order.
+///
+/// # Ex
+/// ```
+/// use seoul::{Tuplike, IntoHList, HList, HCons, HNil, Func, HFold};
+///
+/// #[derive(Debug, Clone, PartialEq, Tuplike)]
+/// struct AB {
+///   a: u8, b: f32, c: String
+/// }
+///
+/// // a polymorphic function summing numeric fields
+/// struct SumNumbers;
+/// impl Func<(f64, u8)> for SumNumbers {
+///   type Output = f64;
+///   fn call(&mut self, (acc, x): (f64, u8)) -> f64 { acc + x as f64 }
+/// }
+/// impl Func<(f64, f32)> for SumNumbers {
+///   type Output = f64;
+///   fn call(&mut self, (acc, x): (f64, f32)) -> f64 { acc + x as f64 }
+/// }
+/// impl Func<(f64, String)> for SumNumbers {
+///   type Output = f64;
+///   fn call(&mut self, (acc, _): (f64, String)) -> f64 { acc }
+/// }
+///
+/// fn sum_all_numeric_fields<T>(t: T) -> f64 where T: IntoHList, T::HList: HFold<SumNumbers, f64> {
+///   t.into_hlist().fold(0.0, &mut SumNumbers)
+/// }
+///
+/// let ab = AB { a: 1, b: 0.5, c: "c".to_string() };
+/// assert_eq!(sum_all_numeric_fields(ab.clone()), 1.5);
+///
+/// let hlist = ab.clone().into_hlist();
+/// assert_eq!(hlist.len(), 3);
+/// assert_eq!(hlist, HCons { head: 1, tail: HCons { head: 0.5, tail: HCons { head: "c".to_string(), tail: HNil } } });
+/// assert_eq!(AB::from_hlist(hlist), ab);
+/// ```
+pub trait HList: Sized {
+
+  /// number of the elements
+  const LEN: usize;
+
+  fn len(&self) -> usize {
+    Self::LEN
+  }
+
+  fn is_empty(&self) -> bool {
+    Self::LEN==0
+  }
+
+  fn prepend<H>(self, head: H) -> HCons<H, Self> {
+    HCons { head, tail: self }
+  }
+}
+
+
+/// The empty heterogeneous list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HNil;
+
+/// A heterogeneous list of a `head` and a `tail` list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HCons<H, T> {
+  pub head: H,
+  pub tail: T,
+}
+
+impl HList for HNil {
+  const LEN: usize = 0;
+}
+
+impl<H, T: HList> HList for HCons<H, T> {
+  const LEN: usize = 1 + T::LEN;
+}
+
+
+/// A polymorphic function: implement it for each argument type.
+pub trait Func<Arg> {
+  type Output;
+
+  fn call(&mut self, arg: Arg) -> Self::Output;
+}
+
+
+/// Map each element with `F: Func<Element>`
+pub trait HMap<F>: HList {
+  type Output: HList;
+
+  fn map(self, f: &mut F) -> Self::Output;
+}
+
+impl<F> HMap<F> for HNil {
+  type Output = HNil;
+
+  fn map(self, _: &mut F) -> Self::Output {
+    HNil
+  }
+}
+
+impl<F, H, T> HMap<F> for HCons<H, T> where F: Func<H>, T: HMap<F> {
+  type Output = HCons<F::Output, T::Output>;
+
+  fn map(self, f: &mut F) -> Self::Output {
+    let head = f.call(self.head);
+    HCons { head, tail: self.tail.map(f) }
+  }
+}
+
+
+/// Fold the elements from the head with `F: Func<(Acc, Element), Output = Acc>`
+pub trait HFold<F, Acc>: HList {
+  fn fold(self, acc: Acc, f: &mut F) -> Acc;
+}
+
+impl<F, Acc> HFold<F, Acc> for HNil {
+  fn fold(self, acc: Acc, _: &mut F) -> Acc {
+    acc
+  }
+}
+
+impl<F, Acc, H, T> HFold<F, Acc> for HCons<H, T> where F: Func<(Acc, H), Output = Acc>, T: HFold<F, Acc> {
+  fn fold(self, acc: Acc, f: &mut F) -> Acc {
+    let acc = f.call((acc, self.head));
+    self.tail.fold(acc, f)
+  }
+}
+
+
+/// # Trait IntoHList
+///
+/// * The `Tuplike` derive macro implements it for structs with the feature `hlist`.
+/// * The elements are the fields in the tuple order: `U` of an `into = U` field, a `flatten` field as a whole,
+///   and `&F` for each field of type `F` in the referenced list.
+pub trait IntoHList: Sized {
+
+  /// heterogeneous list format of the fields
+  type HList: HList;
+
+  /// referenced heterogeneous list format of the fields
+  type RefHList<'a>: HList where Self: 'a;
+
+  fn into_hlist(self) -> Self::HList;
+
+  fn from_hlist(hlist: Self::HList) -> Self;
+
+  fn as_hlist(&self) -> Self::RefHList<'_>;
+}
@@ -6,8 +6,19 @@ pub use seoul_derive::Isomorphism;
 ///   * trait `Isomorphism`
 ///     - `fn title(&self) -> &str;`
 ///     - `fn list() -> Vec<Self>;`
+///     - `fn description(&self) -> Option<&str>;`
+///     - `fn title_in(&self, locale: &str) -> &str;`
+///     - `fn tags(&self) -> &[&str];`
+///     - `fn list_tagged(tag: &str) -> Vec<Self>;`
 ///   * trait `Into<T>` for `&Self` and `Self`
 ///   * trait `From<T>` and `From<&T>` for `Self`, when the type implements trait `Default`
+///   * trait `TryFrom<T>` and `TryFrom<&T>` for `Self`, when **try_from** is given at top level attribute
+///   * inherent `fn list_<name>() -> Vec<Self>` for each named list of top level `lists(<name> = [..], ..)`
+///   * inherent `const fn to_<target>(&self) -> T`, with **const_fn** at top level attribute,
+///     when every variant's into value of `T` is a const expression
+///     (literals, paths, and tuples/arrays/casts of them); `<target>` is the snake case of `T`'s name
+///   * inherent `const fn from_<target>(value: T) -> Option<Self>` for fieldless variants, along with `to_<target>`,
+///     when every fieldless variant's value can be matched in const fn (literals other than `str`, and const paths)
 /// 
 /// * Works only on `Enum` type
 /// 
@@ -16,6 +27,21 @@ pub use seoul_derive::Isomorphism;
 /// * When **list** is not given at the top level attribute, list of each variant's default format will be returned.
 /// * When **into** value is not given at variant level, the into type(T)'s default value will be used in `<Into<T>>` trait.
 /// * When the type implements `Default` and **has_default** is given at top level attribute, the `From<T>` and `From<&T>` will be implemented for each given into types(T). 
+/// * With **try_from** at top level attribute, `TryFrom<T>` and `TryFrom<&T>` will be implemented instead of `From`,
+///   returning the unmatched value as error.
+/// * Variant level **from** gives extra patterns to `From`/`TryFrom` (so it needs **has_default** or **try_from**): `#[from(0..=9 | 42)]`, or `#[from([1..=9, '1'..='9'])]` for type arrays.
+///   It can be given without **into**, for one-way decoding; then `Into<T>` uses the into type's default value.
+/// * With **proptest** / **rand** at top level attribute and the feature of the same name enabled,
+///   `proptest::arbitrary::Arbitrary` / `rand::distr::Distribution<Self>` (of `StandardUniform`) will be implemented.
+///   Fieldless enums draw uniformly from `list()` (an empty one is a compile error); otherwise a variant is drawn uniformly,
+///   and its fields from the field types' own strategies/distributions.
+/// * When **description** is not given at variant level, `description()` returns `None`.
+/// * Variant level **tags** (`#[tags(public, beta)]`) are listed by `list_tagged(tag)`,
+///   in each tagged variant's default format.
+/// * Localized titles are given as `#[title("Red", ko = "빨강", ja = "赤")]`,
+///   or loaded from per-locale files with top level `locale(ko = "locales/ko.txt")`
+///   (path relative to the crate's `Cargo.toml`, lines of `<Variant> = <title>`).
+///   `title_in()` falls back to `title()` when the locale's title is not given.
 /// 
 /// # Ex
 /// ```
@@ -70,10 +96,35 @@ pub use seoul_derive::Isomorphism;
 /// assert_eq!(Into::<CD>::into(-1i8), CD::D);
 /// // fallback to default value of `CD`
 /// assert_eq!(Into::<CD>::into(-0i8), CD::C);
+/// 
+/// 
+/// // localized titles
+/// #[derive(Debug, PartialEq, Isomorphism)]
+/// enum Color {
+///   #[title("Red", ko = "빨강", ja = "赤")] Red,
+///   #[title(ko = "파랑")] Blue,
+/// }
+/// 
+/// assert_eq!(Color::Red.title_in("ja"), "赤");
+/// assert_eq!(Color::Blue.title_in("ko"), "파랑");
+/// assert_eq!(Color::Blue.title_in("ja"), "Blue");
 /// ```
 pub trait Isomorphism: Sized {
 
   fn title(&self) -> &str;
 
   fn list() -> Vec<Self>;
+
+  fn description(&self) -> Option<&str> { None }
+
+  fn title_in(&self, locale: &str) -> &str {
+    let _ = locale;
+    self.title()
+  }
+
+  fn tags(&self) -> &[&str] { &[] }
+
+  fn list_tagged(tag: &str) -> Vec<Self> {
+    Self::list().into_iter().filter(|x| x.tags().contains(&tag)).collect()
+  }
 }
\ No newline at end of file
@@ -3,8 +3,41 @@
 mod isomorphism;
 pub use isomorphism::Isomorphism;
 
+mod transition;
+pub use transition::{Transition, TransitionError};
+
+#[cfg(feature = "export")]
+mod export;
+#[cfg(feature = "export")]
+pub use export::{IsomorphismExport, MappingTable, MappingRow, ExportValue, ToExportValue};
+
+#[cfg(feature = "cli")]
+mod value_enum;
+#[cfg(feature = "cli")]
+pub use value_enum::{ValueEnum, PossibleValue, ValueError, edit_distance};
+
+#[cfg(feature = "proptest")]
+pub use proptest;
+#[cfg(feature = "rand")]
+pub use rand;
+
 mod tuplike;
-pub use tuplike::Tuplike;
+pub use tuplike::{Tuplike, TupleConcat, concat_fields};
+
+#[cfg(feature = "hlist")]
+mod hlist;
+#[cfg(feature = "hlist")]
+pub use hlist::{HList, HNil, HCons, Func, HMap, HFold, IntoHList};
+
+#[cfg(feature = "csv")]
+mod tuple_row;
+#[cfg(feature = "csv")]
+pub use tuple_row::{TupleRow, RowFormat, RowReader, RowWriter, RowError, RowErrorKind};
+
+#[cfg(feature = "encode")]
+mod encode;
+#[cfg(feature = "encode")]
+pub use encode::{Encode, Decode, Decoder, DecodeIter, DecodeError, write_varint};
 
 mod reflica;
 pub use reflica::Reflica;
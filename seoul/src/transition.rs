@@ -0,0 +1,69 @@
+use std::fmt;
+use crate::Isomorphism;
+
+/// # Trait Transition
+///
+/// * State-machine transitions between an enum's variants.
+/// * The `Isomorphism` derive macro implements this trait
+///   when any variant has `#[transition(to = [..])]` attribute.
+///   * Each target of `to` should be a variant name; unknown variants are compile errors.
+///   * `successors()` returns each target variant in its default format
+///     (fields filled with `Default::default()`), like the fallback of `list()`.
+///
+/// # Ex
+/// ```
+/// # use seoul::{Isomorphism, Transition};
+///
+/// #[derive(Debug, PartialEq, Isomorphism)]
+/// enum State {
+///   #[transition(to = [Running])] Idle,
+///   #[transition(to = [Done, Failed])] Running,
+///   Done,
+///   #[transition(to = [Idle])] Failed(String),
+/// }
+///
+/// assert!(State::Idle.can_transition_to(&State::Running));
+/// assert!(!State::Idle.can_transition_to(&State::Done));
+/// assert_eq!(State::Running.successors(), vec![State::Done, State::Failed(String::new())]);
+/// assert!(State::Done.successors().is_empty());
+///
+/// assert_eq!(State::Running.transition(State::Done), Ok(State::Done));
+/// let err = State::Done.transition(State::Idle).unwrap_err();
+/// assert_eq!(err.to_string(), "invalid transition from `Done` to `Idle`");
+///
+/// assert!(State::transition_dot().contains("  Running -> Failed;\n"));
+/// ```
+pub trait Transition: Sized {
+
+  fn can_transition_to(&self, next: &Self) -> bool;
+
+  fn successors(&self) -> Vec<Self>;
+
+  /// Graphviz DOT of the transition graph; nodes are labeled by titles.
+  fn transition_dot() -> String;
+
+  /// Return `next` if the transition is allowed, else both states in `TransitionError`.
+  fn transition(self, next: Self) -> Result<Self, TransitionError<Self>> {
+    if self.can_transition_to(&next) {
+      Ok(next)
+    } else {
+      Err(TransitionError { from: self, to: next })
+    }
+  }
+}
+
+
+/// Error of a not allowed `Transition::transition`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionError<S> {
+  pub from: S,
+  pub to: S,
+}
+
+impl<S: Isomorphism> fmt::Display for TransitionError<S> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid transition from `{}` to `{}`", self.from.title(), self.to.title())
+  }
+}
+
+impl<S: Isomorphism + fmt::Debug> std::error::Error for TransitionError<S> { }
@@ -0,0 +1,303 @@
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::marker::PhantomData;
+use crate::Tuplike;
+
+/// # Trait TupleRow
+///
+/// * Enabled with the feature `csv`.
+/// * A delimited text row of a struct's fields in the tuple order:
+///   each field is written with `Display` and parsed with `FromStr`.
+/// * The `Tuplike` derive macro implements it with the struct level `#[tuplike(row)]` attribute.
+///   * Not for `flatten` or `into`/`with` fields.
+/// * `RowReader` reads rows from a `BufRead`, and `RowWriter` writes them (with a header of `FIELD_NAMES`) to a `Write`.
+///
+/// # Ex
+/// ```
+/// use seoul::{Tuplike, TupleRow, RowFormat, RowReader, RowWriter};
+///
+/// #[derive(Debug, Clone, PartialEq, Tuplike)]
+/// #[tuplike(row)]
+/// struct Item {
+///   id: u32, name: String, price: f32
+/// }
+///
+/// let item = Item { id: 1, name: "a, b".to_string(), price: 0.5 };
+/// let format = RowFormat::default();
+/// assert_eq!(item.to_row(&format), "1,\"a, b\",0.5");
+/// assert_eq!(Item::from_row("1,\"a, b\",0.5", &format).unwrap(), item);
+///
+/// let mut writer = RowWriter::new(Vec::new(), RowFormat::default()).unwrap();
+/// writer.write(&item).unwrap();
+/// let text = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(text, "id,name,price\n1,\"a, b\",0.5\n");
+///
+/// let text = "id\tname\tprice\n1\ta\t0.5\n2\tb\tx\n";
+/// let rows: Vec<_> = RowReader::<_, Item>::new(text.as_bytes(), RowFormat::new('\t')).collect();
+/// assert_eq!(rows[0].as_ref().unwrap(), &Item { id: 1, name: "a".to_string(), price: 0.5 });
+///
+/// let err = rows[1].as_ref().unwrap_err();
+/// assert_eq!((err.line, err.column), (3, Some(3)));
+/// assert_eq!(err.to_string(), "line 3, column 3 (`price`): invalid float literal");
+/// ```
+pub trait TupleRow: Tuplike {
+
+  /// fields formatted with `Display`, in the tuple order
+  fn to_fields(&self) -> Vec<String>;
+
+  /// parse the fields with `FromStr`, in the tuple order
+  /// * errors are of line 1
+  fn from_fields(fields: &[&str]) -> Result<Self, RowError>;
+
+  fn to_row(&self, format: &RowFormat) -> String {
+    format.join(&self.to_fields())
+  }
+
+  fn from_row(row: &str, format: &RowFormat) -> Result<Self, RowError> {
+    let fields = format.split(row).map_err(|kind| RowError { line: 1, column: None, kind })?;
+    Self::from_fields(&fields.iter().map(|x| x.as_str()).collect::<Vec<_>>())
+  }
+
+  /// header row of `FIELD_NAMES`
+  fn header(format: &RowFormat) -> String {
+    format.join(Self::FIELD_NAMES)
+  }
+}
+
+
+/// Delimiter and quoting of rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowFormat {
+  pub delimiter: char,
+  /// a field containing the delimiter, the quote or a line break is quoted, doubling the quote inside.
+  /// `None` writes the fields as they are.
+  pub quote: Option<char>,
+  /// whether the first line is a header: `RowReader` skips it, and `RowWriter` writes it
+  pub header: bool,
+}
+
+impl Default for RowFormat {
+  /// `,` delimiter, `"` quote, with a header
+  fn default() -> Self {
+    Self { delimiter: ',', quote: Some('"'), header: true }
+  }
+}
+
+impl RowFormat {
+
+  /// the delimiter with `"` quote and a header
+  pub fn new(delimiter: char) -> Self {
+    Self { delimiter, ..Default::default() }
+  }
+
+  pub fn quote(mut self, quote: Option<char>) -> Self {
+    self.quote = quote;
+    self
+  }
+
+  pub fn header(mut self, header: bool) -> Self {
+    self.header = header;
+    self
+  }
+
+  /// join the fields into a row, quoting them if needed
+  /// * a lone empty field is quoted, not to be read as a blank line
+  pub fn join<S: AsRef<str>>(&self, fields: &[S]) -> String {
+    let mut out = String::new();
+    let lone = fields.len()==1;
+    for (i, field) in fields.iter().enumerate() {
+      if i > 0 { out.push(self.delimiter); }
+      let field = field.as_ref();
+      match self.quote {
+        Some(q) if field.contains([self.delimiter, q, '\n', '\r']) || (lone && field.is_empty()) => {
+          out.push(q);
+          for c in field.chars() {
+            if c==q { out.push(q); }
+            out.push(c);
+          }
+          out.push(q);
+        },
+        _ => out.push_str(field),
+      }
+    }
+    out
+  }
+
+  /// split a row into the fields, unquoting them
+  pub fn split(&self, row: &str) -> Result<Vec<String>, RowErrorKind> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut start = true;
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+      if quoted {
+        if Some(c)==self.quote {
+          if chars.peek()==Some(&c) {
+            chars.next();
+            field.push(c);
+          } else {
+            quoted = false;
+          }
+        } else {
+          field.push(c);
+        }
+      } else if c==self.delimiter {
+        fields.push(std::mem::take(&mut field));
+        start = true;
+        continue;
+      } else if start && Some(c)==self.quote {
+        quoted = true;
+      } else {
+        field.push(c);
+      }
+      start = false;
+    }
+
+    if quoted {
+      return Err(RowErrorKind::UnclosedQuote)
+    }
+    fields.push(field);
+    Ok(fields)
+  }
+}
+
+
+/// An error of a row, at a line and a column (both 1-based; the column in the tuple order)
+#[derive(Debug)]
+pub struct RowError {
+  pub line: usize,
+  pub column: Option<usize>,
+  pub kind: RowErrorKind,
+}
+
+#[derive(Debug)]
+pub enum RowErrorKind {
+  Io(io::Error),
+  UnclosedQuote,
+  /// number of the fields
+  Length { expected: usize, found: usize },
+  /// `FromStr` error of a field
+  Parse { field: &'static str, message: String },
+}
+
+impl RowError {
+
+  /// `Length` error of line 1
+  pub fn length(expected: usize, found: usize) -> Self {
+    Self { line: 1, column: None, kind: RowErrorKind::Length { expected, found } }
+  }
+
+  /// `Parse` error of line 1
+  pub fn parse(column: usize, field: &'static str, message: impl fmt::Display) -> Self {
+    Self { line: 1, column: Some(column), kind: RowErrorKind::Parse { field, message: message.to_string() } }
+  }
+}
+
+impl fmt::Display for RowError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "line {}", self.line)?;
+    if let Some(column) = self.column {
+      write!(f, ", column {}", column)?;
+    }
+    match &self.kind {
+      RowErrorKind::Io(err) => write!(f, ": {}", err),
+      RowErrorKind::UnclosedQuote => write!(f, ": unclosed quote"),
+      RowErrorKind::Length { expected, found } => write!(f, ": expected {} fields, found {}", expected, found),
+      RowErrorKind::Parse { field, message } => write!(f, " (`{}`): {}", field, message),
+    }
+  }
+}
+
+impl std::error::Error for RowError {}
+
+
+/// Iterator of rows from a `BufRead`
+/// * skips the header line by `RowFormat::header`, and empty lines (unless `T` has a single field).
+/// * a quoted field may continue over line breaks.
+pub struct RowReader<R, T> {
+  lines: io::Lines<R>,
+  format: RowFormat,
+  line: usize,
+  _marker: PhantomData<T>,
+}
+
+impl<R: BufRead, T: TupleRow> RowReader<R, T> {
+
+  pub fn new(reader: R, format: RowFormat) -> Self {
+    let mut lines = reader.lines();
+    let mut line = 0;
+    if format.header {
+      lines.next();
+      line += 1;
+    }
+    Self { lines, format, line, _marker: PhantomData }
+  }
+}
+
+impl<R: BufRead, T: TupleRow> Iterator for RowReader<R, T> {
+  type Item = Result<T, RowError>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut row = String::new();
+    let mut start = 0;
+
+    loop {
+      let Some(next) = self.lines.next() else {
+        // the input ends in a quoted field
+        return (start > 0).then(|| Err(RowError { line: start, column: None, kind: RowErrorKind::UnclosedQuote }))
+      };
+      self.line += 1;
+      let next = match next {
+        Ok(x) => x,
+        Err(err) => return Some(Err(RowError { line: self.line, column: None, kind: RowErrorKind::Io(err) })),
+      };
+      let next = next.strip_suffix('\r').unwrap_or(&next);
+
+      if start==0 {
+        if next.is_empty() && T::FIELD_NAMES.len()!=1 { continue; }
+        start = self.line;
+      } else {
+        row.push('\n');
+      }
+      row.push_str(next);
+
+      match self.format.split(&row) {
+        Err(RowErrorKind::UnclosedQuote) => continue,
+        Err(kind) => return Some(Err(RowError { line: start, column: None, kind })),
+        Ok(fields) => {
+          let fields = fields.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+          return Some(T::from_fields(&fields).map_err(|err| RowError { line: start, ..err }))
+        }
+      }
+    }
+  }
+}
+
+
+/// Writer of rows into a `Write`, with a header by `RowFormat::header`
+pub struct RowWriter<W, T> {
+  writer: W,
+  format: RowFormat,
+  _marker: PhantomData<T>,
+}
+
+impl<W: Write, T: TupleRow> RowWriter<W, T> {
+
+  /// writes the header
+  pub fn new(mut writer: W, format: RowFormat) -> io::Result<Self> {
+    if format.header {
+      writeln!(writer, "{}", T::header(&format))?;
+    }
+    Ok(Self { writer, format, _marker: PhantomData })
+  }
+
+  pub fn write(&mut self, row: &T) -> io::Result<()> {
+    writeln!(self.writer, "{}", row.to_row(&self.format))
+  }
+
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+}
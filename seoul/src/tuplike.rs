@@ -6,13 +6,59 @@ pub use seoul_derive::Tuplike;
   * `AB { a: 0, b: 10 }` <=> `(0, 10)`
 
 * Using derive macro, you can implement
+  * trait `Tuplike` with `type Tuple = T`, `type RefTuple<'a> = R` and `type MutTuple<'a> = M`
+    and `const FIELD_NAMES: &'static [&'static str]` (`"0"`, `"1"`, .. for tuple structs),
+    `const FIELD_TYPES: &'static [&'static str]` of the fields in the tuple order
   * trait `From<T>` for `Self`
   * trait `Into<T>` for `Self`
   * trait `Into<R>` for `&Self`
+  * trait `Into<M>` for `&mut Self`
   * Hereby, `T` is a tuple format of the struct's fields,
-  * and the `R` is a referenced tuple format of them.
-
-* for the **enum** data type, Only `From<T>` trait will be implemted for each variant.
+  * the `R` is a referenced tuple format of them,
+  * and the `M` is a mutably referenced tuple format of them.
+  * Zero field (and unit) structs have `()` for all of them.
+
+* Field attributes
+  * `#[tuplike(skip)]`: the field is omitted from the tuple formats, and filled with `Default::default()` in building.
+  * `#[tuplike(skip, default = expr)]`: the skipped field is filled with the `expr` instead.
+  * `#[tuplike(flatten)]`: the field's own `Tuplike` tuple formats are inlined (via `TupleConcat`),
+    e.g. `(f32, f32, f32, f32)` for `Line { from: Point, to: Point }`.
+    The flat shape is only named by `Tuplike::Tuple`, so such a struct gets the trait `Tuplike` without the `From`/`Into` impls.
+    `FIELD_NAMES` and `FIELD_TYPES` list the flattened field's own ones.
+  * `#[tuplike(into = U, with = module)]`: the tuple holds `U` for the field of type `F`, converted with
+    `module::to(F) -> U` and `module::from(U) -> F`. The referenced tuples keep `&F`.
+
+* Struct attributes
+  * `#[tuplike(order = [b, a])]`: field order of the tuple formats (`[1, 0]` for tuple structs).
+  * `#[tuplike(projection(key = [a, b]))]`: inherent `fn key(&self) -> (&A, &B)` and `fn into_key(self) -> (A, B)`.
+  * `#[tuplike(array)]`: for fields of one type `T`, `From<[T; N]>` for `Self`, `Into<[T; N]>` for `Self`,
+    `Into<[&T; N]>` for `&Self` and inherent `fn iter_fields(&self) -> impl Iterator<Item = &T>`.
+  * `#[tuplike(chunks)]`: nested tuple formats of 12 field chunks, `((f0, .., f11), (f12, ..))`,
+    so that structs wider than 12 fields still get the std tuple trait impls (`Debug`, `PartialEq`, `Hash`, `Default`, ..).
+  * `#[tuplike(cons)]`: cons-list tuple formats, `(a, (b, (c, ())))`.
+  * `#[tuplike(columns)]`: companion struct of arrays `<Name>Columns` (`Debug`, `Clone`, `PartialEq`), a `Vec` for each field in the tuple order,
+    with `From<Vec<Self>>`, `Into<Vec<Self>>`, `fn push(&mut self, row: Self)`, `fn len(&self)` (of the shortest column),
+    `fn get(&self, index: usize) -> Option<R>` and `fn iter(&self) -> impl Iterator<Item = R>`.
+  * `#[tuplike(convert(Other, ..))]`: `From<Self>` for `Other` and `From<Other>` for `Self` through the tuple format,
+    with a static assertion that `Other: Tuplike` has the same `Tuple`.
+
+* With the feature `hlist`, trait `IntoHList` for the struct:
+  `fn into_hlist(self)`, `fn from_hlist(hlist)` and `fn as_hlist(&self)` of `HCons<A, HCons<B, HNil>>` in the tuple order.
+* With the feature `csv` and the struct attribute `#[tuplike(row)]`, trait `TupleRow` for delimited text rows
+  (`Display`/`FromStr` of each field), read by `RowReader` and written by `RowWriter`.
+* With the feature `encode` and the attribute `#[tuplike(encode)]` (for structs and enums), traits `Encode`/`Decode`
+  of a compact binary format: the fields in the tuple order (skipped fields are left out and decoded as default),
+  led by the variant index for enums. Not for `into`/`with` fields.
+
+* for the **enum** data type, the trait `Tuplike` itself is not implemented. For each variant (`()` for a unit variant),
+  * trait `From<T>` for `Self`
+  * trait `TryFrom<Self>` for `T`, returning the original value as error on a mismatch
+    (skipped when `T` has generic type parameters, by the orphan rule)
+  * inherent `fn from_<variant>_tuple(tuple: T) -> Self`,
+    `fn into_<variant>_tuple(self) -> Option<T>` and `fn as_<variant>_tuple(&self) -> Option<R>`
+  * inherent `const <VARIANT>_FIELD_NAMES` and `const <VARIANT>_FIELD_TYPES`
+  * Variants of an identical tuple shape are compile errors, since their `From`/`TryFrom` impls would conflict.
+    `#[tuplike(skip)]` on a variant opts out of the trait impls, leaving the inherent methods.
 
 ## Example
 ```rust
@@ -34,8 +80,23 @@ assert_eq!(&tuple_into, &ab_);
 
 let _ab_ref_into: (&u8, &String) = (&ab_).into();
 
+// trait methods, for generic code
+fn to_row<T: Tuplike>(t: &T) -> T::RefTuple<'_> {
+  t.as_tuple()
+}
+let _: (&u8, &String) = to_row(&ab_);
+let _: <AB as Tuplike>::Tuple = ab_.clone().into_tuple();
+let _: AB = AB::from_tuple(tuple_.clone());
+
+// disjoint mutable borrows of the fields
+let mut ab_ = ab_;
+let (a, b): (&mut u8, &mut String) = ab_.as_tuple_mut();
+*a += 1;
+b.push_str("!");
+assert_eq!(ab_, AB { a: 1, b: "string!".to_string() });
 
-// for enum, just `From<T>` will be implemented for each variant.
+
+// for enum, `From<T>` and the reverse will be implemented for each variant.
 #[derive(Debug, Clone, PartialEq, Tuplike)]
 enum ABC {
   A,
@@ -57,6 +118,127 @@ let c2: ABC = ABC::C { a: 10, b: "string".to_string() };
 let c1_: ABC = c1.clone().into();
 
 assert_eq!(&c1_, &c2);
+
+let c3: Result<(i32, String), ABC> = c2.clone().try_into();
+assert_eq!(c3, Ok(c1.clone()));
+assert_eq!(c2.as_c_tuple(), Some((&10, &"string".to_string())));
+assert_eq!(c2.into_b_tuple(), None);
 ```
  */
-pub trait Tuplike: Sized { }
\ No newline at end of file
+pub trait Tuplike: Sized {
+
+  /// tuple format of the fields
+  type Tuple;
+
+  /// referenced tuple format of the fields
+  type RefTuple<'a> where Self: 'a;
+
+  /// mutably referenced tuple format of the fields
+  type MutTuple<'a> where Self: 'a;
+
+  /// names of the fields in the tuple order (`"0"`, `"1"`, .. for tuple structs)
+  const FIELD_NAMES: &'static [&'static str];
+
+  /// stringified types of the fields in the tuple order
+  const FIELD_TYPES: &'static [&'static str];
+
+  fn into_tuple(self) -> Self::Tuple;
+
+  fn from_tuple(tuple: Self::Tuple) -> Self;
+
+  fn as_tuple(&self) -> Self::RefTuple<'_>;
+
+  fn as_tuple_mut(&mut self) -> Self::MutTuple<'_>;
+}
+
+/// `FIELD_NAMES`/`FIELD_TYPES` of the parts of a flattened tuple, concatenated in the first `.1` entries
+/// * used by the `Tuplike` derive macro for `#[tuplike(flatten)]` fields, up to 12 in total like `TupleConcat`
+#[doc(hidden)]
+pub const fn concat_fields(parts: &[&'static [&'static str]]) -> ([&'static str; 12], usize) {
+  let mut fields = [""; 12];
+  let mut len = 0;
+  let mut i = 0;
+  while i < parts.len() {
+    let mut j = 0;
+    while j < parts[i].len() {
+      assert!(len < 12, "more than 12 fields to flatten");
+      fields[len] = parts[i][j];
+      len += 1;
+      j += 1;
+    }
+    i += 1;
+  }
+  (fields, len)
+}
+
+
+/// # Trait TupleConcat
+///
+/// * Concatenate two tuples into one flat tuple, and split it back.
+/// * Implemented for tuples whose total length is up to 12.
+/// * The `Tuplike` derive macro uses it for `#[tuplike(flatten)]` fields.
+///
+/// # Ex
+/// ```
+/// use seoul::TupleConcat;
+///
+/// let ab: (u8, i8, String) = (1u8, -1i8).concat(("c".to_string(),));
+/// assert_eq!(ab, (1, -1, "c".to_string()));
+/// assert_eq!(<(u8, i8)>::split(ab), ((1, -1), ("c".to_string(),)));
+/// ```
+pub trait TupleConcat<Rhs>: Sized {
+
+  type Output;
+
+  fn concat(self, rhs: Rhs) -> Self::Output;
+
+  fn split(output: Self::Output) -> (Self, Rhs);
+}
+
+
+macro_rules! impl_tuple_concat {
+  ([$($a:ident)*] [$($b:ident)*]) => {
+    #[allow(non_snake_case, clippy::unused_unit)]
+    impl<$($a,)* $($b,)*> TupleConcat<($($b,)*)> for ($($a,)*) {
+      type Output = ($($a,)* $($b,)*);
+
+      fn concat(self, rhs: ($($b,)*)) -> Self::Output {
+        let ($($a,)*) = self;
+        let ($($b,)*) = rhs;
+        ($($a,)* $($b,)*)
+      }
+
+      fn split(output: Self::Output) -> (Self, ($($b,)*)) {
+        let ($($a,)* $($b,)*) = output;
+        (($($a,)*), ($($b,)*))
+      }
+    }
+  };
+}
+
+// every rhs from `()` up to the whole pool
+macro_rules! impl_tuple_concat_rhs {
+  ([$($a:ident)*] [$($b:ident)*]) => {
+    impl_tuple_concat!([$($a)*] [$($b)*]);
+  };
+  ([$($a:ident)*] [$($b:ident)*] $next:ident $($rest:ident)*) => {
+    impl_tuple_concat!([$($a)*] [$($b)*]);
+    impl_tuple_concat_rhs!([$($a)*] [$($b)* $next] $($rest)*);
+  };
+}
+
+// every lhs, each taking an element off the rhs pool to keep the total length
+macro_rules! impl_tuple_concat_lhs {
+  ([$($a:ident)*] [$($b:ident)*]) => {
+    impl_tuple_concat_rhs!([$($a)*] [] $($b)*);
+  };
+  ([$($a:ident)*] [$b0:ident $($b:ident)*] $next:ident $($rest:ident)*) => {
+    impl_tuple_concat_rhs!([$($a)*] [] $b0 $($b)*);
+    impl_tuple_concat_lhs!([$($a)* $next] [$($b)*] $($rest)*);
+  };
+}
+
+impl_tuple_concat_lhs!(
+  [] [B0 B1 B2 B3 B4 B5 B6 B7 B8 B9 B10 B11]
+  A0 A1 A2 A3 A4 A5 A6 A7 A8 A9 A10 A11
+);
@@ -0,0 +1,129 @@
+use std::fmt;
+use crate::Isomorphism;
+
+/// # Trait ValueEnum
+///
+/// * Enabled with the feature `cli`.
+/// * Command-line value parsing of an `Isomorphism` enum, built on `list()` and `title()`.
+///   Only the values of `list()` can be parsed.
+/// * The `Isomorphism` derive macro implements this trait along with `Isomorphism`,
+///   taking aliases from variant level `#[alias("..", ..)]` attributes.
+///
+/// # Ex
+/// ```
+/// # use seoul::{Isomorphism, ValueEnum};
+///
+/// #[derive(Debug, PartialEq, Isomorphism)]
+/// enum Color {
+///   #[title("red")] #[alias("r")] #[description("the color of fire")] Red,
+///   #[title("blue")] Blue,
+/// }
+///
+/// assert_eq!(Color::parse_value("red"), Ok(Color::Red));
+/// assert_eq!(Color::parse_value("r"), Ok(Color::Red));
+///
+/// let err = Color::parse_value("bleu").unwrap_err();
+/// assert_eq!(err.suggestion.as_deref(), Some("blue"));
+///
+/// assert_eq!(Color::help(), "possible values:\n  - red, r: the color of fire\n  - blue\n");
+/// ```
+pub trait ValueEnum: Isomorphism {
+
+  fn aliases(&self) -> &[&str] { &[] }
+
+  /// Parse from the title or an alias of a value of `list()`.
+  fn parse_value(input: &str) -> Result<Self, ValueError> {
+    let list = Self::list();
+
+    if let Some(i) = list.iter().position(|x| x.title()==input || x.aliases().contains(&input)) {
+      return Ok(list.into_iter().nth(i).unwrap());
+    }
+
+    let possible: Vec<String> = list.iter().map(|x| x.title().to_string()).collect();
+    let suggestion = list.iter()
+      .flat_map(|x| std::iter::once(x.title()).chain(x.aliases().iter().copied()).map(move |name| (x.title(), name)))
+      .map(|(title, name)| (edit_distance(input, name), title))
+      .filter(|(distance, _)| *distance <= (input.chars().count()/3).max(2))
+      .min_by_key(|(distance, _)| *distance)
+      .map(|(_, title)| title.to_string());
+
+    Err(ValueError { input: input.to_string(), possible, suggestion })
+  }
+
+  /// Title, aliases and description of each value of `list()`.
+  fn possible_values() -> Vec<PossibleValue> {
+    Self::list().iter().map(|x| PossibleValue {
+      title: x.title().to_string(),
+      aliases: x.aliases().iter().map(|x| x.to_string()).collect(),
+      description: x.description().map(|x| x.to_string()),
+    }).collect()
+  }
+
+  /// Help text of the possible values, for `--help`.
+  fn help() -> String {
+    let mut out = String::from("possible values:\n");
+    for value in Self::possible_values() {
+      out.push_str("  - ");
+      out.push_str(&value.title);
+      for alias in value.aliases.iter() {
+        out.push_str(", ");
+        out.push_str(alias);
+      }
+      if let Some(description) = value.description {
+        out.push_str(": ");
+        out.push_str(&description);
+      }
+      out.push('\n');
+    }
+    out
+  }
+}
+
+
+/// A possible value of `ValueEnum`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PossibleValue {
+  pub title: String,
+  pub aliases: Vec<String>,
+  pub description: Option<String>,
+}
+
+/// Error of `ValueEnum::parse_value`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueError {
+  pub input: String,
+  /// titles of the possible values
+  pub possible: Vec<String>,
+  /// the nearest title by edit distance, if it is near enough
+  pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ValueError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid value '{}' [possible values: {}]", self.input, self.possible.join(", "))?;
+    if let Some(suggestion) = &self.suggestion {
+      write!(f, "\n  tip: a similar value exists: '{}'", suggestion)?;
+    }
+    Ok(())
+  }
+}
+
+impl std::error::Error for ValueError { }
+
+
+/// Levenshtein distance of two strings, by chars
+pub fn edit_distance(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut prev: Vec<usize> = (0..=b.len()).collect();
+  let mut curr: Vec<usize> = vec![0; b.len()+1];
+
+  for (i, ca) in a.chars().enumerate() {
+    curr[0] = i+1;
+    for (j, cb) in b.iter().enumerate() {
+      let cost = if ca==*cb { 0 } else { 1 };
+      curr[j+1] = (prev[j]+cost).min(prev[j+1]+1).min(curr[j]+1);
+    }
+    std::mem::swap(&mut prev, &mut curr);
+  }
+  prev[b.len()]
+}
@@ -146,4 +146,403 @@ fn test_isomorphism_temp1() {
       Into::<bool>::into(self)
     }
   }
-}
\ No newline at end of file
+}
+
+/// description
+#[test]
+fn test_isomorphism_description() {
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  enum AB {
+    #[description("the first")] A,
+    B
+  }
+
+  assert_eq!(AB::A.description(), Some("the first"));
+  assert_eq!(AB::B.description(), None);
+}
+
+
+/// mapping table export
+#[cfg(feature = "export")]
+#[test]
+fn test_isomorphism_export() {
+
+  type StaticStr = &'static str;
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(into=[u8, bool, StaticStr])]
+  enum CD {
+    #[into([0, true, "c"])] #[title("C, c")] #[description("say \"c\"")] C,
+    #[into([1, false, "d"])] D(i32),
+  }
+
+  let table = CD::mapping_table();
+  assert_eq!(table.name, "CD");
+  assert_eq!(table.columns, vec!["u8", "bool", "StaticStr"]);
+  assert_eq!(table.rows[1].values, vec![ExportValue::Number("1".to_string()), ExportValue::Bool(false), ExportValue::Str("d".to_string())]);
+
+  assert_eq!(
+    CD::export_csv(),
+    "variant,title,u8,bool,StaticStr,description\nC,\"C, c\",0,true,c,\"say \"\"c\"\"\"\nD,D,1,false,d,\n"
+  );
+  assert_eq!(
+    CD::export_json(),
+    r#"[{"variant":"C","title":"C, c","u8":0,"bool":true,"StaticStr":"c","description":"say \"c\""},{"variant":"D","title":"D","u8":1,"bool":false,"StaticStr":"d","description":null}]"#
+  );
+  assert_eq!(
+    CD::export_typescript(),
+    concat!(
+      "export enum CD {\n  C = \"C, c\",\n  D = \"D\",\n}\n\n",
+      "export type CDTitle = \"C, c\" | \"D\";\n\n",
+      "export const CDTable = {\n",
+      "  C: { title: \"C, c\", \"u8\": 0, \"bool\": true, \"StaticStr\": \"c\", description: \"say \\\"c\\\"\" },\n",
+      "  D: { title: \"D\", \"u8\": 1, \"bool\": false, \"StaticStr\": \"d\", description: null },\n",
+      "} as const;\n",
+    )
+  );
+
+  // without into types, fallback into values are exported as default
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(u8)]
+  enum EF { #[into(3)] E, F }
+
+  assert_eq!(EF::export_csv(), "variant,title,u8,description\nE,E,3,\nF,F,0,\n");
+}
+
+
+/// state-machine transitions
+#[test]
+fn test_isomorphism_transition() {
+
+  #[derive(Debug, Clone, PartialEq, Isomorphism)]
+  enum State<X: Default> {
+    #[title("idle")] #[transition(to = [Running])] Idle,
+    #[transition(to = [Done, Failed])] #[transition(to = [Idle])] Running(X),
+    Done,
+    #[transition(to = [Idle])] Failed { reason: String },
+  }
+
+  type S = State<u8>;
+
+  assert!(S::Idle.can_transition_to(&S::Running(3)));
+  assert!(!S::Idle.can_transition_to(&S::Done));
+  assert!(!S::Done.can_transition_to(&S::Idle));
+  assert!(S::Running(1).can_transition_to(&S::Idle));
+
+  assert_eq!(S::Idle.successors(), vec![S::Running(0)]);
+  assert_eq!(S::Running(1).successors(), vec![S::Done, S::Failed { reason: String::new() }, S::Idle]);
+  assert!(S::Done.successors().is_empty());
+
+  assert_eq!(S::Idle.transition(S::Running(5)), Ok(S::Running(5)));
+  let err = S::Running(5).transition(S::Running(6)).unwrap_err();
+  assert_eq!(err, TransitionError { from: S::Running(5), to: S::Running(6) });
+  assert_eq!(err.to_string(), "invalid transition from `Running` to `Running`");
+
+  assert_eq!(
+    S::transition_dot(),
+    concat!(
+      "digraph State {\n",
+      "  Idle [label=\"idle\"];\n  Running [label=\"Running\"];\n  Done [label=\"Done\"];\n  Failed [label=\"Failed\"];\n",
+      "  Idle -> Running;\n  Running -> Done;\n  Running -> Failed;\n  Running -> Idle;\n  Failed -> Idle;\n",
+      "}\n",
+    )
+  );
+}
+
+
+/// localized titles
+#[test]
+fn test_isomorphism_title_in() {
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  enum Color {
+    #[title("Red", ko = "빨강", ja = "赤")] Red,
+    #[title(ko = "파랑", "zh-TW" = "藍")] Blue(u8),
+    Green,
+  }
+
+  assert_eq!(Color::Red.title(), "Red");
+  assert_eq!(Color::Red.title_in("ko"), "빨강");
+  assert_eq!(Color::Red.title_in("ja"), "赤");
+  assert_eq!(Color::Blue(0).title(), "Blue");
+  assert_eq!(Color::Blue(0).title_in("ko"), "파랑");
+  assert_eq!(Color::Blue(0).title_in("zh-TW"), "藍");
+  // fallback to the default title
+  assert_eq!(Color::Blue(0).title_in("ja"), "Blue");
+  assert_eq!(Color::Green.title_in("ko"), "Green");
+
+  // from locale files
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(locale(ko = "tests/locales/ko.txt", ja = "tests/locales/ja.txt"))]
+  enum Fruit {
+    Apple,
+    #[title("banana", ja = "バナナ")] Banana,
+    Cherry,
+  }
+
+  assert_eq!(Fruit::Apple.title_in("ko"), "사과");
+  assert_eq!(Fruit::Apple.title_in("ja"), "りんご");
+  assert_eq!(Fruit::Banana.title_in("ko"), "바나나");
+  assert_eq!(Fruit::Banana.title_in("ja"), "バナナ");
+  assert_eq!(Fruit::Banana.title_in("en"), "banana");
+  assert_eq!(Fruit::Cherry.title_in("ko"), "Cherry");
+}
+
+
+/// command-line value parsing
+#[cfg(feature = "cli")]
+#[test]
+fn test_isomorphism_value_enum() {
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(list=[Fast, Slow(1)])]
+  enum Mode {
+    #[title("fast")] #[alias("f", "quick")] #[description("skip checks")] Fast,
+    #[title("slow")] Slow(u8),
+    #[title("hidden")] Hidden,
+  }
+
+  assert_eq!(Mode::parse_value("fast"), Ok(Mode::Fast));
+  assert_eq!(Mode::parse_value("quick"), Ok(Mode::Fast));
+  assert_eq!(Mode::parse_value("slow"), Ok(Mode::Slow(1)));
+  assert_eq!(Mode::Fast.aliases(), &["f", "quick"]);
+  assert!(Mode::Hidden.aliases().is_empty());
+
+  // only values of `list()` can be parsed
+  let err = Mode::parse_value("hidden").unwrap_err();
+  assert_eq!(err.possible, vec!["fast", "slow"]);
+
+  let err = Mode::parse_value("slwo").unwrap_err();
+  assert_eq!(err.suggestion.as_deref(), Some("slow"));
+  assert_eq!(err.to_string(), "invalid value 'slwo' [possible values: fast, slow]\n  tip: a similar value exists: 'slow'");
+
+  // suggestion through an alias
+  let err = Mode::parse_value("quik").unwrap_err();
+  assert_eq!(err.suggestion.as_deref(), Some("fast"));
+
+  let err = Mode::parse_value("something").unwrap_err();
+  assert_eq!(err.suggestion, None);
+
+  assert_eq!(Mode::possible_values()[0], PossibleValue {
+    title: "fast".to_string(), aliases: vec!["f".to_string(), "quick".to_string()], description: Some("skip checks".to_string())
+  });
+  assert_eq!(Mode::help(), "possible values:\n  - fast, f, quick: skip checks\n  - slow\n");
+
+  assert_eq!(edit_distance("kitten", "sitting"), 3);
+  assert_eq!(edit_distance("", "abc"), 3);
+}
+
+
+/// const fn conversions
+#[test]
+fn test_isomorphism_const_fn() {
+
+  type StaticStr = &'static str;
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(into=[u8, i8, StaticStr, f32], const_fn)]
+  enum CD {
+    #[into([0, 1, "c", 0.5])] C,
+    #[into([1, -1, "d", 1.5])] D,
+    #[into([2, -2, "e", 2.5])] E(u8),
+  }
+
+  const C_U8: u8 = CD::C.to_u8();
+  const D_STR: &str = CD::D.to_static_str();
+  const TABLE: [i8; 2] = [CD::C.to_i8(), CD::D.to_i8()];
+  const LEN: usize = CD::D.to_u8() as usize + 1;
+
+  assert_eq!(C_U8, 0);
+  assert_eq!(D_STR, "d");
+  assert_eq!(TABLE, [1, -1]);
+  assert_eq!([0u8; LEN].len(), 2);
+  assert_eq!(CD::E(3).to_f32(), 2.5);
+
+  // only fieldless variants
+  const D: Option<CD> = CD::from_i8(-1);
+  assert_eq!(D, Some(CD::D));
+  assert_eq!(CD::from_u8(0), Some(CD::C));
+  assert_eq!(CD::from_u8(2), None);
+  assert_eq!(CD::from_f32(1.5), Some(CD::D));
+
+  // const paths are matched back
+  const TWO: u8 = 2;
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(u8, const_fn)]
+  enum GH { #[into(1)] G, #[into(TWO)] H }
+
+  assert_eq!(GH::H.to_u8(), 2);
+  assert_eq!(GH::from_u8(GH::G.to_u8()), Some(GH::G));
+  assert_eq!(GH::from_u8(GH::H.to_u8()), Some(GH::H));
+  assert_eq!(GH::from_u8(3), None);
+
+  // not generated without `const_fn`, nor `to_<target>` for omitted values,
+  // nor `from_<target>` for `str` values or casts
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(u8)]
+  enum EF { #[into(3)] E, F }
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(u8, const_fn)]
+  enum IJ { #[into(1)] I, #[into(2u16 as u8)] J }
+
+  trait ToU8 { fn to_u8(&self) -> u8 { 10 } }
+  impl ToU8 for EF { }
+  assert_eq!(EF::E.to_u8(), 10);
+
+  trait FromU8 { fn from_u8(_: u8) -> Option<Self> where Self: Sized { None } }
+  impl FromU8 for IJ { }
+  assert_eq!(IJ::J.to_u8(), 2);
+  assert_eq!(IJ::from_u8(1), None);
+}
+
+
+/// pattern based reverse mapping
+#[test]
+fn test_isomorphism_from_patterns() {
+
+  // with `has_default` (From)
+  #[derive(Debug, Default, PartialEq, Isomorphism)]
+  #[isomorphism(u16, has_default)]
+  enum Status {
+    #[into(200)] #[from(201..=299)] Ok,
+    #[into(404)] #[from(400 | 410)] NotFound,
+    // one-way decoding: `Into` falls back to the default value
+    #[from(500..=599)] ServerError,
+    #[default] Unknown,
+  }
+
+  assert_eq!(Status::from(200), Status::Ok);
+  assert_eq!(Status::from(204), Status::Ok);
+  assert_eq!(Status::from(&410), Status::NotFound);
+  assert_eq!(Status::from(503), Status::ServerError);
+  assert_eq!(Status::from(302), Status::Unknown);
+  assert_eq!(Into::<u16>::into(Status::NotFound), 404);
+  assert_eq!(Into::<u16>::into(Status::ServerError), 0);
+
+  // with `try_from` (TryFrom), for each into type
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(into=[u8, char], try_from)]
+  enum Digit {
+    #[into([0, '0'])] Zero,
+    #[from([1..=9, '1'..='9'])] NonZero,
+    #[into([255, 'x'])] #[from([10..=254, _])] Invalid(u8),
+  }
+
+  assert_eq!(Digit::try_from(0u8), Ok(Digit::Zero));
+  assert_eq!(Digit::try_from(7u8), Ok(Digit::NonZero));
+  assert_eq!(Digit::try_from(&100u8), Ok(Digit::Invalid(0)));
+  assert_eq!(Digit::try_from('5'), Ok(Digit::NonZero));
+  assert_eq!(Digit::try_from('a'), Ok(Digit::Invalid(0)));
+  assert_eq!(Into::<char>::into(Digit::NonZero), '\0');
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(i32, try_from)]
+  enum Sign {
+    #[into(1)] #[from(1..)] Plus,
+    #[into(-1)] #[from(..=-1)] Minus,
+  }
+
+  assert_eq!(Sign::try_from(10), Ok(Sign::Plus));
+  assert_eq!(Sign::try_from(-3), Ok(Sign::Minus));
+  assert_eq!(Sign::try_from(0), Err(0));
+  assert_eq!(Sign::try_from(&0), Err(&0));
+}
+
+
+/// random sampling
+#[cfg(feature = "rand")]
+#[test]
+fn test_isomorphism_rand() {
+  use seoul::rand::{Rng, SeedableRng, rngs::StdRng};
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(list=[A, C], rand)]
+  enum ABC { A, B, C }
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(rand)]
+  enum Shape<X: Default> {
+    Empty,
+    Dot(X),
+    Rect { w: u8, h: u8 },
+  }
+
+  let mut rng = StdRng::seed_from_u64(7);
+
+  // fieldless: uniform from `list()`
+  let samples: Vec<ABC> = (0..100).map(|_| rng.random()).collect();
+  assert!(samples.contains(&ABC::A));
+  assert!(samples.contains(&ABC::C));
+  assert!(!samples.contains(&ABC::B));
+
+  // data-carrying: every variant with random fields
+  let samples: Vec<Shape<bool>> = (0..100).map(|_| rng.random()).collect();
+  assert!(samples.contains(&Shape::Empty));
+  assert!(samples.iter().any(|x| matches!(x, Shape::Dot(_))));
+  assert!(samples.iter().any(|x| matches!(x, Shape::Rect { w, h } if w != h)));
+}
+
+
+/// property-testing strategies
+#[cfg(feature = "proptest")]
+mod test_isomorphism_proptest {
+  use seoul::*;
+  use seoul::proptest::prelude::*;
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(u8, list=[A, C], proptest)]
+  enum ABC { #[into(0)] A, #[into(1)] B, #[into(2)] C }
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(proptest)]
+  enum Shape<X: Default> {
+    Empty,
+    Dot(X),
+    Rect { w: u8, h: u8 },
+  }
+
+  proptest! {
+    #[test]
+    fn fieldless_from_list(x in any::<ABC>()) {
+      prop_assert!(Into::<u8>::into(&x) != 1);
+      prop_assert_ne!(x, ABC::B);
+    }
+
+    #[test]
+    fn data_carrying(x in any::<Shape<String>>()) {
+      let title = x.title().to_string();
+      match x {
+        Shape::Empty => prop_assert_eq!(title, "Empty"),
+        Shape::Dot(_) => prop_assert_eq!(title, "Dot"),
+        Shape::Rect { .. } => prop_assert_eq!(title, "Rect"),
+      }
+    }
+  }
+}
+
+
+/// named lists and tags
+#[test]
+fn test_isomorphism_lists() {
+
+  #[derive(Debug, PartialEq, Isomorphism)]
+  #[isomorphism(list=[A, B(1)], lists(public = [A, B(2)], admin = [A, B(3), C]))]
+  enum ABC {
+    #[tags(public, beta)] A,
+    #[tags(public)] B(u8),
+    #[tags(beta, beta)] C,
+  }
+
+  assert_eq!(ABC::list(), vec![ABC::A, ABC::B(1)]);
+  assert_eq!(ABC::list_public(), vec![ABC::A, ABC::B(2)]);
+  assert_eq!(ABC::list_admin(), vec![ABC::A, ABC::B(3), ABC::C]);
+
+  assert_eq!(ABC::A.tags(), &["public", "beta"]);
+  assert_eq!(ABC::C.tags(), &["beta"]);
+  assert_eq!(ABC::list_tagged("public"), vec![ABC::A, ABC::B(0)]);
+  assert_eq!(ABC::list_tagged("beta"), vec![ABC::A, ABC::C]);
+  assert!(ABC::list_tagged("other").is_empty());
+}
@@ -11,7 +11,7 @@ fn test_tuplike1() {
   let tuple_: (u8, String) = (0, "string".to_string());
   let ab_: AB = AB { a: 0, b: "string".to_string() };
 
-  //let _: <AB as Tuplike>::Tuple = ab_.clone().into();
+  let _: <AB as Tuplike>::Tuple = ab_.clone().into();
 
   let ab_into: (u8, String) = ab_.clone().into();
   let tuple_into: AB = tuple_.clone().into();
@@ -31,7 +31,7 @@ fn test_tuplike2() {
   let tuple_: (u8, String) = (0, "string".to_string());
   let ab_: AB = AB(0, "string".to_string());
 
-  //let _: <AB as Tuplike>::Tuple = ab_.clone().into();
+  let _: <AB as Tuplike>::Tuple = ab_.clone().into();
 
   let ab_into: (u8, String) = ab_.clone().into();
   let tuple_into: AB = tuple_.clone().into();
@@ -54,7 +54,7 @@ fn test_tuplike3() {
   let tuple_: (u8, String) = (0, "string".to_string());
   let ab_: AB<String> = AB { a: 0, b: "string".to_string() };
 
-  //let _: <AB<String> as Tuplike>::Tuple = ab_.clone().into();
+  let _: <AB<String> as Tuplike>::Tuple = ab_.clone().into();
 
   let ab_into: (u8, String) = ab_.clone().into();
   let tuple_into: AB<String> = tuple_.clone().into();
@@ -75,7 +75,7 @@ fn test_tuplike4() {
   let tuple_: (u8, String) = (0, "string".to_string());
   let ab_: AB<u8, String> = AB { a: 0, b: "string".to_string() };
 
-  //let _: <AB<u8, String> as Tuplike>::Tuple = ab_.clone().into();
+  let _: <AB<u8, String> as Tuplike>::Tuple = ab_.clone().into();
 
   let ab_into: (u8, String) = ab_.clone().into();
   let tuple_into: AB<u8, String> = tuple_.clone().into();
@@ -109,4 +109,850 @@ fn test_tuplike5() {
   let c1_: ABC = c1.clone().into();
 
   assert_eq!(&c1_, &c2);
-}
\ No newline at end of file
+}
+
+
+/// trait `Tuplike` in generic code
+#[test]
+fn test_tuplike_trait() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct AB<X: Clone> {
+    a: u8, b: X
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct CD(i32, String);
+
+  fn to_row<T: Tuplike>(t: &T) -> T::RefTuple<'_> {
+    t.as_tuple()
+  }
+
+  fn round_trip<T: Tuplike>(t: T) -> T {
+    T::from_tuple(t.into_tuple())
+  }
+
+  let ab = AB { a: 1, b: vec![0u8] };
+  assert_eq!(to_row(&ab), (&1, &vec![0u8]));
+  assert_eq!(round_trip(ab.clone()), ab);
+
+  let cd = CD(-1, "cd".to_string());
+  let tuple: <CD as Tuplike>::Tuple = cd.clone().into_tuple();
+  assert_eq!(tuple, (-1, "cd".to_string()));
+  assert_eq!(CD::from_tuple(tuple), cd);
+  assert_eq!(cd.as_tuple(), (&-1, &"cd".to_string()));
+}
+
+
+/// mutable reference tuples
+#[test]
+fn test_tuplike_mut() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Pair<X> {
+    left: X, right: X
+  }
+
+  fn swap<T, X>(t: &mut T) where T: Tuplike, for<'a> T::MutTuple<'a>: Into<(&'a mut X, &'a mut X)> {
+    let (a, b) = t.as_tuple_mut().into();
+    std::mem::swap(a, b);
+  }
+
+  let mut pair = Pair { left: "l".to_string(), right: "r".to_string() };
+  swap(&mut pair);
+  assert_eq!(pair, Pair { left: "r".to_string(), right: "l".to_string() });
+
+  let (left, _): (&mut String, &mut String) = (&mut pair).into();
+  left.push('!');
+  assert_eq!(pair.left, "r!");
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct AB(u8, Vec<u8>);
+
+  let mut ab = AB(1, vec![]);
+  let (a, b) = ab.as_tuple_mut();
+  b.push(*a);
+  *a = 2;
+  assert_eq!(ab, AB(2, vec![1]));
+}
+
+
+/// reverse direction for enum variants
+#[test]
+fn test_tuplike_enum_reverse() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum ABC {
+    A,
+    B(String),
+    C { a: i32, b: String }
+  }
+
+  let c = ABC::C { a: 10, b: "c".to_string() };
+
+  // TryFrom<Self> for each variant's tuple
+  let c1: Result<(i32, String), ABC> = c.clone().try_into();
+  assert_eq!(c1, Ok((10, "c".to_string())));
+  let b1: Result<(String,), ABC> = c.clone().try_into();
+  assert_eq!(b1, Err(c.clone()));
+
+  // inherent methods
+  assert_eq!(c.as_c_tuple(), Some((&10, &"c".to_string())));
+  assert_eq!(c.as_b_tuple(), None);
+  assert_eq!(c.clone().into_c_tuple(), Some((10, "c".to_string())));
+  assert_eq!(ABC::A.into_b_tuple(), None);
+  assert_eq!(ABC::B("b".to_string()).into_b_tuple(), Some(("b".to_string(),)));
+
+  // round trip
+  let x: ABC = c.clone().into_c_tuple().unwrap().into();
+  assert_eq!(x, c);
+
+  // generics: only the inherent methods for tuples with type parameters
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum Wrap<X> {
+    One(X),
+    Code { code: u8, sub: u8 },
+  }
+
+  let x: Wrap<String> = Wrap::One("x".to_string());
+  assert_eq!(x.as_one_tuple(), Some((&"x".to_string(),)));
+  assert_eq!(x.clone().into_code_tuple(), None);
+  let code: Result<(u8, u8), _> = x.try_into();
+  assert_eq!(code, Err(Wrap::One("x".to_string())));
+
+  let code: Result<(u8, u8), _> = Wrap::<String>::Code { code: 1, sub: 2 }.try_into();
+  assert_eq!(code, Ok((1, 2)));
+}
+
+
+/// variants sharing a tuple shape
+#[test]
+fn test_tuplike_enum_shape() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum AB {
+    A(u8),
+    #[tuplike(skip)] B(u8),
+    C { c: u16 },
+  }
+
+  // `From`/`TryFrom` for the first one
+  let a: AB = (1u8,).into();
+  assert_eq!(a, AB::A(1));
+  let x: Result<(u8,), AB> = AB::B(2).try_into();
+  assert_eq!(x, Err(AB::B(2)));
+
+  // named constructors are always generated
+  assert_eq!(AB::from_a_tuple((1,)), AB::A(1));
+  assert_eq!(AB::from_b_tuple((2,)), AB::B(2));
+  assert_eq!(AB::B(2).as_b_tuple(), Some((&2,)));
+
+  // This case won't compile: `C` has the same tuple shape `(u8,)` as `A`.
+  /*#[derive(Tuplike)]
+  enum CD {
+    A(u8),
+    C { c: u8 },
+  }*/
+}
+
+
+/// skipped fields
+#[test]
+fn test_tuplike_skip() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Cached<X> {
+    key: u32,
+    #[tuplike(skip)] cache: Option<String>,
+    #[tuplike(skip, default = 7)] hits: usize,
+    value: X,
+    #[tuplike(skip)] _marker: std::marker::PhantomData<X>,
+  }
+
+  let x: Cached<String> = (1, "v".to_string()).into();
+  assert_eq!(x, Cached { key: 1, cache: None, hits: 7, value: "v".to_string(), _marker: std::marker::PhantomData });
+
+  let tuple: <Cached<String> as Tuplike>::Tuple = x.clone().into_tuple();
+  assert_eq!(tuple, (1, "v".to_string()));
+  assert_eq!(x.as_tuple(), (&1, &"v".to_string()));
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct AB(u8, #[tuplike(skip, default = String::from("b"))] String, i8);
+
+  let ab: AB = (1, -1).into();
+  assert_eq!(ab, AB(1, "b".to_string(), -1));
+  let mut ab = ab;
+  let (a, c): (&mut u8, &mut i8) = (&mut ab).into();
+  *a = 2;
+  *c = -2;
+  assert_eq!(ab, AB(2, "b".to_string(), -2));
+
+  // enum variants
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum CD {
+    C(u8, #[tuplike(skip)] u16),
+    D { d: String, #[tuplike(skip)] len: usize },
+  }
+
+  let c: CD = (1u8,).into();
+  assert_eq!(c, CD::C(1, 0));
+  assert_eq!(CD::C(1, 5).into_c_tuple(), Some((1,)));
+  assert_eq!(CD::D { d: "d".to_string(), len: 1 }.as_d_tuple(), Some((&"d".to_string(),)));
+  let d: Result<(String,), CD> = CD::D { d: "d".to_string(), len: 1 }.try_into();
+  assert_eq!(d, Ok(("d".to_string(),)));
+}
+
+
+/// field order and named projections
+#[test]
+fn test_tuplike_order() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(order = [version, id, name])]
+  #[tuplike(projection(key = [id, version], label = [name]))]
+  struct Doc {
+    id: u64,
+    name: String,
+    version: u32,
+    #[tuplike(skip)] dirty: bool,
+  }
+
+  let doc = Doc { id: 1, name: "doc".to_string(), version: 2, dirty: false };
+
+  let tuple: (u32, u64, String) = doc.clone().into();
+  assert_eq!(tuple, (2, 1, "doc".to_string()));
+  assert_eq!(Doc::from_tuple(tuple), doc);
+  assert_eq!(doc.as_tuple(), (&2, &1, &"doc".to_string()));
+
+  assert_eq!(doc.key(), (&1, &2));
+  assert_eq!(doc.label(), (&"doc".to_string(),));
+  assert_eq!(doc.into_key(), (1, 2));
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(order = [2, 0, 1], projection(tail = [1, 2]))]
+  struct Triple(u8, i8, String);
+
+  let abc: Triple = ("c".to_string(), 1, -1).into();
+  assert_eq!(abc, Triple(1, -1, "c".to_string()));
+  assert_eq!(abc.tail(), (&-1, &"c".to_string()));
+  let mut abc = abc;
+  let (c, a, _) = abc.as_tuple_mut();
+  c.push('!');
+  *a += 1;
+  assert_eq!(abc, Triple(2, -1, "c!".to_string()));
+}
+
+
+/// homogeneous fields to and from arrays
+#[test]
+fn test_tuplike_array() {
+
+  #[derive(Debug, Clone, Copy, PartialEq, Tuplike)]
+  #[tuplike(array)]
+  struct Rgb {
+    r: u8, g: u8, b: u8
+  }
+
+  let rgb: Rgb = [1, 2, 3].into();
+  assert_eq!(rgb, Rgb { r: 1, g: 2, b: 3 });
+  let array: [u8; 3] = rgb.into();
+  assert_eq!(array, [1, 2, 3]);
+  let refs: [&u8; 3] = (&rgb).into();
+  assert_eq!(refs, [&1, &2, &3]);
+  assert_eq!(rgb.iter_fields().map(|x| *x as u32).sum::<u32>(), 6);
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(array, order = [z, y, x])]
+  struct Vec3<T> {
+    x: T, y: T, z: T,
+    #[tuplike(skip)] norm: Option<f32>,
+  }
+
+  let v: Vec3<f32> = [3.0, 2.0, 1.0].into();
+  assert_eq!(v, Vec3 { x: 1.0, y: 2.0, z: 3.0, norm: None });
+  assert_eq!(v.iter_fields().copied().collect::<Vec<_>>(), vec![3.0, 2.0, 1.0]);
+  let array: [f32; 3] = v.into();
+  assert_eq!(array, [3.0, 2.0, 1.0]);
+
+  // heterogeneous fields are compile errors
+  // #[derive(Tuplike)]
+  // #[tuplike(array)]
+  // struct AB { a: u8, b: u16 }
+}
+
+
+/// zero fields and unit variants as `()`
+#[test]
+fn test_tuplike_unit() {
+
+  #[derive(Debug, Clone, PartialEq, Default, Tuplike)]
+  struct Marker;
+
+  #[derive(Debug, Clone, PartialEq, Default, Tuplike)]
+  struct Empty {}
+
+  #[derive(Debug, Clone, PartialEq, Default, Tuplike)]
+  struct Only {
+    #[tuplike(skip)] _cache: Option<u8>,
+  }
+
+  fn unit<T: Tuplike<Tuple = ()>>() -> T {
+    T::from_tuple(())
+  }
+
+  assert_eq!(unit::<Marker>(), Marker);
+  assert_eq!(unit::<Empty>(), Empty {});
+  assert_eq!(unit::<Only>(), Only::default());
+  let _: () = Marker.into();
+  let _: () = (&Empty {}).into();
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum State {
+    Idle,
+    Running(u32),
+    #[tuplike(skip)] Done,
+  }
+
+  let idle: State = ().into();
+  assert_eq!(idle, State::Idle);
+  let unit: Result<(), State> = State::Idle.try_into();
+  assert_eq!(unit, Ok(()));
+  assert_eq!(State::Running(1).into_idle_tuple(), None);
+  assert_eq!(State::from_done_tuple(()), State::Done);
+  assert_eq!(State::Done.as_done_tuple(), Some(()));
+
+  // unit variants without `skip` make the same shape `()`, compile errors
+  // #[derive(Tuplike)]
+  // enum AB { A, B }
+}
+
+
+/// flattened fields
+#[test]
+fn test_tuplike_flatten() {
+
+  #[derive(Debug, Clone, Copy, PartialEq, Tuplike)]
+  struct Point {
+    x: f32, y: f32
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Line {
+    #[tuplike(flatten)] from: Point,
+    #[tuplike(flatten)] to: Point,
+  }
+
+  let line = Line { from: Point { x: 0.0, y: 1.0 }, to: Point { x: 2.0, y: 3.0 } };
+
+  let tuple: (f32, f32, f32, f32) = line.clone().into_tuple();
+  assert_eq!(tuple, (0.0, 1.0, 2.0, 3.0));
+  assert_eq!(Line::from_tuple(tuple), line);
+  let refs: (&f32, &f32, &f32, &f32) = line.as_tuple();
+  assert_eq!(refs, (&0.0, &1.0, &2.0, &3.0));
+
+  let mut line = line;
+  let (x, _, _, y) = line.as_tuple_mut();
+  *x = 5.0;
+  *y = 6.0;
+  assert_eq!(line, Line { from: Point { x: 5.0, y: 1.0 }, to: Point { x: 2.0, y: 6.0 } });
+
+  // mixed with other fields, skipped fields and order
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(order = [1, 0])]
+  struct Label(#[tuplike(flatten)] Point, String, #[tuplike(skip)] bool);
+
+  let label = Label::from_tuple(("a".to_string(), 1.0, 2.0));
+  assert_eq!(label, Label(Point { x: 1.0, y: 2.0 }, "a".to_string(), false));
+  assert_eq!(label.into_tuple(), ("a".to_string(), 1.0, 2.0));
+}
+
+
+/// wide structs in nested tuple shapes
+#[test]
+fn test_tuplike_nested() {
+
+  #[derive(Debug, Clone, PartialEq, Default, Tuplike)]
+  #[tuplike(chunks)]
+  struct Wide {
+    a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8,
+    h: u8, i: u8, j: u8, k: u8, l: u8, m: u16, n: String,
+  }
+
+  type Chunks = ((u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8), (u16, String));
+
+  let wide = Wide { a: 1, n: "n".to_string(), ..Default::default() };
+  let tuple: Chunks = wide.clone().into();
+  assert_eq!(tuple, ((1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0), (0, "n".to_string())));
+  assert_eq!(format!("{:?}", tuple.1), "(0, \"n\")");
+  assert_eq!(Wide::from_tuple(tuple.clone()), wide);
+  assert_eq!(Chunks::default(), Wide::default().into_tuple());
+
+  let mut set = std::collections::HashSet::new();
+  set.insert(tuple);
+  assert!(set.contains(&wide.clone().into_tuple()));
+
+  let ((a, ..), (_, n)) = wide.as_tuple();
+  assert_eq!((a, n), (&1, &"n".to_string()));
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(cons)]
+  struct Cons(u8, i8, String);
+
+  let cons: Cons = (1, (-1, ("c".to_string(), ()))).into();
+  assert_eq!(cons, Cons(1, -1, "c".to_string()));
+  let (a, (b, (c, ()))) = cons.as_tuple();
+  assert_eq!((a, b, c), (&1, &-1, &"c".to_string()));
+}
+
+
+/// struct of arrays
+#[test]
+fn test_tuplike_columns() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(columns)]
+  struct AB {
+    a: u8, b: String,
+    #[tuplike(skip)] c: bool,
+  }
+
+  let rows = vec![
+    AB { a: 1, b: "x".to_string(), c: false },
+    AB { a: 2, b: "y".to_string(), c: false },
+  ];
+
+  let mut columns: ABColumns = rows.clone().into();
+  assert_eq!(columns, ABColumns { a: vec![1, 2], b: vec!["x".to_string(), "y".to_string()] });
+  assert_eq!(columns.len(), 2);
+  assert_eq!(columns.get(1), Some((&2, &"y".to_string())));
+  assert_eq!(columns.get(2), None);
+
+  columns.push(AB { a: 3, b: "z".to_string(), c: true });
+  assert_eq!(columns.iter().map(|(a, _)| *a as u32).sum::<u32>(), 6);
+
+  // a column pushed directly: rows up to the shortest column
+  columns.a.push(4);
+  assert_eq!(columns.len(), 3);
+  assert_eq!(columns.get(3), None);
+  assert_eq!(columns.iter().count(), 3);
+  columns.a.pop();
+
+  let rows_: Vec<AB> = columns.into();
+  assert_eq!(&rows_[..2], &rows[..]);
+  assert_eq!(rows_[2], AB { a: 3, b: "z".to_string(), c: false });
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(columns, order = [1, 0])]
+  struct Pair<X>(X, i32);
+
+  let columns = PairColumns::from(vec![Pair("a", 1), Pair("b", 2)]);
+  assert_eq!(columns, PairColumns(vec![1, 2], vec!["a", "b"]));
+  assert_eq!(format!("{:?}", columns), "PairColumns([1, 2], [\"a\", \"b\"])");
+  assert_eq!(columns.clone().len(), 2);
+  assert!(!columns.is_empty());
+  assert_eq!(columns.iter().collect::<Vec<_>>(), vec![(&1, &"a"), (&2, &"b")]);
+  assert!(PairColumns::<u8>::default().is_empty());
+}
+
+
+/// field conversion adapters
+#[test]
+fn test_tuplike_with() {
+  use std::time::Duration;
+  use std::path::PathBuf;
+
+  mod millis {
+    use std::time::Duration;
+    pub fn to(x: Duration) -> u64 { x.as_millis() as u64 }
+    pub fn from(x: u64) -> Duration { Duration::from_millis(x) }
+  }
+
+  mod path_string {
+    use std::path::PathBuf;
+    pub fn to(x: PathBuf) -> String { x.to_string_lossy().into_owned() }
+    pub fn from(x: String) -> PathBuf { PathBuf::from(x) }
+  }
+
+  mod id {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Id(pub u32);
+    pub fn to(x: Id) -> u32 { x.0 }
+    pub fn from(x: u32) -> Id { Id(x) }
+  }
+  use id::Id;
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Job {
+    #[tuplike(into = u32, with = id)] id: Id,
+    #[tuplike(into = u64, with = millis)] timeout: Duration,
+    #[tuplike(into = String, with = path_string)] path: PathBuf,
+  }
+
+  let job = Job { id: Id(1), timeout: Duration::from_secs(2), path: PathBuf::from("/tmp") };
+
+  let tuple: (u32, u64, String) = job.clone().into();
+  assert_eq!(tuple, (1, 2000, "/tmp".to_string()));
+  assert_eq!(Job::from(tuple), job);
+
+  // ref tuples keep the field types
+  let (id, timeout, _): (&Id, &Duration, &PathBuf) = job.as_tuple();
+  assert_eq!((id, timeout), (&Id(1), &Duration::from_secs(2)));
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum Event {
+    Wait(#[tuplike(into = u64, with = millis)] Duration),
+    Open { #[tuplike(into = u32, with = id)] id: Id, retry: bool },
+  }
+
+  let wait: Event = (500u64,).into();
+  assert_eq!(wait, Event::Wait(Duration::from_millis(500)));
+  assert_eq!(wait.as_wait_tuple(), Some((&Duration::from_millis(500),)));
+  assert_eq!(wait.into_wait_tuple(), Some((500,)));
+  let open: Result<(u32, bool), Event> = Event::Open { id: Id(3), retry: true }.try_into();
+  assert_eq!(open, Ok((3, true)));
+}
+
+
+/// struct to struct conversions through the tuple
+#[test]
+fn test_tuplike_convert() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct UserDto {
+    id: u64, name: String,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct UserRow(u64, String);
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(convert(UserDto, UserRow))]
+  struct User {
+    id: u64, name: String,
+    #[tuplike(skip)] cache: Option<usize>,
+  }
+
+  let user = User { id: 1, name: "a".to_string(), cache: Some(1) };
+
+  let dto: UserDto = user.clone().into();
+  assert_eq!(dto, UserDto { id: 1, name: "a".to_string() });
+  let row = UserRow::from(user);
+  assert_eq!(row, UserRow(1, "a".to_string()));
+
+  let user: User = row.into();
+  assert_eq!(user, User { id: 1, name: "a".to_string(), cache: None });
+  assert_eq!(User::from(dto), user);
+
+  // a struct of a different tuple shape is a compile error
+  // #[derive(Tuplike)]
+  // #[tuplike(convert(UserDto))]
+  // struct Other { id: u32, name: String }
+}
+
+
+/// heterogeneous lists
+#[cfg(feature = "hlist")]
+#[test]
+fn test_tuplike_hlist() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct AB {
+    a: u8,
+    #[tuplike(skip)] cache: Option<u8>,
+    b: String,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(order = [1, 0])]
+  struct CD(i32, String);
+
+  // a polymorphic function validating every field
+  struct NonEmpty;
+  impl<'a> Func<(bool, &'a u8)> for NonEmpty {
+    type Output = bool;
+    fn call(&mut self, (acc, x): (bool, &'a u8)) -> bool { acc && *x > 0 }
+  }
+  impl<'a> Func<(bool, &'a i32)> for NonEmpty {
+    type Output = bool;
+    fn call(&mut self, (acc, x): (bool, &'a i32)) -> bool { acc && *x != 0 }
+  }
+  impl<'a> Func<(bool, &'a String)> for NonEmpty {
+    type Output = bool;
+    fn call(&mut self, (acc, x): (bool, &'a String)) -> bool { acc && !x.is_empty() }
+  }
+
+  fn validate_all_fields<T>(t: &T) -> bool where T: IntoHList, for<'a> T::RefHList<'a>: HFold<NonEmpty, bool> {
+    t.as_hlist().fold(true, &mut NonEmpty)
+  }
+
+  let ab = AB { a: 1, cache: Some(1), b: "b".to_string() };
+  assert!(validate_all_fields(&ab));
+  assert!(!validate_all_fields(&AB { a: 0, cache: None, b: "b".to_string() }));
+  assert!(validate_all_fields(&CD(1, "d".to_string())));
+  assert!(!validate_all_fields(&CD(1, String::new())));
+
+  // map with a polymorphic function
+  struct Show;
+  impl Func<u8> for Show {
+    type Output = String;
+    fn call(&mut self, x: u8) -> String { x.to_string() }
+  }
+  impl Func<String> for Show {
+    type Output = String;
+    fn call(&mut self, x: String) -> String { x }
+  }
+
+  let hlist = ab.clone().into_hlist();
+  assert_eq!(hlist.len(), 2);
+  let shown = hlist.clone().map(&mut Show);
+  assert_eq!(shown, HCons { head: "1".to_string(), tail: HCons { head: "b".to_string(), tail: HNil } });
+  assert_eq!(AB::from_hlist(hlist), AB { cache: None, ..ab });
+
+  let cd = CD(2, "d".to_string()).into_hlist();
+  assert_eq!(cd, HNil.prepend(2).prepend("d".to_string()));
+}
+
+
+/// field metadata
+#[test]
+fn test_tuplike_fields() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(order = [b, a, d])]
+  struct AB {
+    a: u8,
+    b: Vec<(u8, String)>,
+    #[tuplike(skip)] _c: Option<&'static str>,
+    #[tuplike(into = u64, with = millis)] d: std::time::Duration,
+  }
+
+  mod millis {
+    use std::time::Duration;
+    pub fn to(x: Duration) -> u64 { x.as_millis() as u64 }
+    pub fn from(x: u64) -> Duration { Duration::from_millis(x) }
+  }
+
+  assert_eq!(AB::FIELD_NAMES, &["b", "a", "d"]);
+  assert_eq!(AB::FIELD_TYPES, &["Vec<(u8, String)>", "u8", "u64"]);
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct CD(i32, #[tuplike(skip)] bool, [u8; 4]);
+
+  fn headers<T: Tuplike>() -> String {
+    T::FIELD_NAMES.join(",")
+  }
+  assert_eq!(headers::<CD>(), "0,2");
+  assert_eq!(CD::FIELD_TYPES, &["i32", "[u8; 4]"]);
+
+  // flattened fields list their own fields, matching the flat tuple
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Point { x: f32, y: f32 }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Line {
+    #[tuplike(flatten)] from: Point,
+    #[tuplike(flatten)] to: Point,
+    width: u8,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  struct Tagged(&'static str, #[tuplike(flatten)] CD);
+
+  assert_eq!(Line::FIELD_NAMES, &["x", "y", "x", "y", "width"]);
+  assert_eq!(Line::FIELD_TYPES, &["f32", "f32", "f32", "f32", "u8"]);
+  assert_eq!(Tagged::FIELD_NAMES, &["0", "0", "2"]);
+  assert_eq!(Tagged::FIELD_TYPES, &["&'static str", "i32", "[u8; 4]"]);
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  enum Shape {
+    Dot,
+    Circle(f32),
+    RoundRect { width: f32, height: f32, radius: Option<f32> },
+  }
+
+  assert!(Shape::DOT_FIELD_NAMES.is_empty());
+  assert_eq!(Shape::CIRCLE_FIELD_NAMES, &["0"]);
+  assert_eq!(Shape::ROUND_RECT_FIELD_NAMES, &["width", "height", "radius"]);
+  assert_eq!(Shape::ROUND_RECT_FIELD_TYPES, &["f32", "f32", "Option<f32>"]);
+}
+
+
+/// delimited text rows
+#[cfg(feature = "csv")]
+#[test]
+fn test_tuplike_row() {
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(row, order = [name, id])]
+  struct Item {
+    id: u32,
+    name: String,
+    #[tuplike(skip)] _cache: Option<u8>,
+  }
+
+  let items = vec![
+    Item { id: 1, name: "plain".to_string(), _cache: None },
+    Item { id: 2, name: "say \"hi\", then\nbye".to_string(), _cache: None },
+  ];
+
+  let mut writer = RowWriter::new(Vec::new(), RowFormat::default()).unwrap();
+  for item in items.iter() {
+    writer.write(item).unwrap();
+  }
+  let text = String::from_utf8(writer.into_inner()).unwrap();
+  assert_eq!(text, "name,id\nplain,1\n\"say \"\"hi\"\", then\nbye\",2\n");
+
+  let rows = RowReader::<_, Item>::new(text.as_bytes(), RowFormat::default())
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+  assert_eq!(rows, items);
+
+  // errors with the line and the column
+  let text = "name,id\r\n\r\na,1\r\nb\r\nc,-1\r\n\"d,2\n";
+  let rows = RowReader::<_, Item>::new(text.as_bytes(), RowFormat::default()).collect::<Vec<_>>();
+  assert_eq!(rows.len(), 4);
+  assert_eq!(rows[0].as_ref().unwrap().name, "a");
+
+  let err = rows[1].as_ref().unwrap_err();
+  assert_eq!((err.line, err.column), (4, None));
+  assert!(matches!(err.kind, RowErrorKind::Length { expected: 2, found: 1 }));
+  assert_eq!(err.to_string(), "line 4: expected 2 fields, found 1");
+
+  let err = rows[2].as_ref().unwrap_err();
+  assert_eq!((err.line, err.column), (5, Some(2)));
+  assert_eq!(err.to_string().split(':').next(), Some("line 5, column 2 (`id`)"));
+  assert!(matches!(err.kind, RowErrorKind::Parse { field: "id", .. }));
+
+  let err = rows[3].as_ref().unwrap_err();
+  assert_eq!(err.line, 6);
+  assert!(matches!(err.kind, RowErrorKind::UnclosedQuote));
+
+  // custom delimiter, no quoting, no header
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(row)]
+  struct Pair(i8, f64);
+
+  let format = RowFormat::new('|').quote(None).header(false);
+  assert_eq!(Pair(-1, 0.25).to_row(&format), "-1|0.25");
+  assert_eq!(Pair::from_row("3|1e3", &format).unwrap(), Pair(3, 1000.0));
+  assert_eq!(Pair::header(&RowFormat::new(';')), "0;1");
+  let rows: Vec<Pair> = RowReader::new("1|2\n3|4\n".as_bytes(), format).map(|x| x.unwrap()).collect();
+  assert_eq!(rows, vec![Pair(1, 2.0), Pair(3, 4.0)]);
+
+  // empty rows of a single field survive the round trip
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(row)]
+  struct Note { text: String }
+
+  let notes = ["a", "", "b"].map(|x| Note { text: x.to_string() });
+  let mut writer = RowWriter::new(Vec::new(), RowFormat::default()).unwrap();
+  for note in notes.iter() {
+    writer.write(note).unwrap();
+  }
+  let text = String::from_utf8(writer.into_inner()).unwrap();
+  assert_eq!(text, "text\na\n\"\"\nb\n");
+  let rows: Vec<Note> = RowReader::new(text.as_bytes(), RowFormat::default()).map(|x| x.unwrap()).collect();
+  assert_eq!(rows, notes);
+
+  let format = RowFormat::default().quote(None).header(false);
+  let rows: Vec<Note> = RowReader::new("a\n\nb\n".as_bytes(), format).map(|x| x.unwrap()).collect();
+  assert_eq!(rows, notes);
+
+  // generic fields
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(row)]
+  struct Tagged<T> { id: u8, value: T }
+
+  let format = RowFormat::default();
+  assert_eq!(Tagged { id: 1, value: 'x' }.to_row(&format), "1,x");
+  assert_eq!(Tagged::<f64>::from_row("2,0.5", &format).unwrap(), Tagged { id: 2, value: 0.5 });
+  assert_eq!(Tagged::<u8>::from_row("2,-1", &format).unwrap_err().column, Some(2));
+}
+
+
+#[cfg(feature = "encode")]
+#[test]
+fn test_tuplike_encode() {
+  use std::collections::BTreeMap;
+  use seoul::{Encode, Decode, Decoder, DecodeError};
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(encode)]
+  struct Record {
+    id: i32,
+    #[tuplike(skip)] cache: Option<String>,
+    flag: bool,
+    initial: char,
+    note: Option<String>,
+    counts: BTreeMap<String, u16>,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(encode)]
+  enum Event {
+    Start,
+    Record(Record),
+    Move { dx: i8, dy: i64 },
+    #[tuplike(skip)] Stop(u8),
+  }
+
+  let record = Record {
+    id: -2,
+    cache: Some("x".to_string()),
+    flag: true,
+    initial: 'k',
+    note: None,
+    counts: BTreeMap::from([("a".to_string(), 300)]),
+  };
+
+  // zigzag varint, bool, char, none, a map of one entry
+  let bytes = record.to_bytes();
+  assert_eq!(bytes, vec![3, 1, b'k', 0, 1, 1, b'a', 0xac, 0x02]);
+  assert_eq!(Record::from_bytes(&bytes).unwrap(), Record { cache: None, ..record.clone() });
+
+  let events = [
+    Event::Start,
+    Event::Record(record.clone()),
+    Event::Move { dx: -1, dy: i64::MIN },
+    Event::Stop(7),
+  ];
+  assert_eq!(Event::Start.to_bytes(), vec![0]);
+  assert_eq!(Event::Stop(7).to_bytes(), vec![3, 7]);
+
+  // streaming
+  let mut bytes = Vec::new();
+  for event in events.iter() {
+    event.encode(&mut bytes);
+  }
+  let decoded = Decoder::new(bytes.as_slice()).iter::<Event>().collect::<Result<Vec<_>, _>>().unwrap();
+  assert_eq!(decoded[0], Event::Start);
+  assert_eq!(decoded[1], Event::Record(Record { cache: None, ..record.clone() }));
+  assert_eq!(decoded[2..], events[2..]);
+
+  // errors
+  let mut decoder = Decoder::new(&bytes[..bytes.len()-1]);
+  for _ in 0..3 {
+    decoder.decode::<Event>().unwrap();
+  }
+  assert!(matches!(decoder.decode::<Event>(), Err(DecodeError::Truncated { position: 24 })));
+
+  assert!(matches!(Event::from_bytes(&[4]), Err(DecodeError::InvalidVariant { position: 0, index: 4 })));
+  assert!(matches!(Event::from_bytes(&[0, 0]), Err(DecodeError::Trailing { position: 1 })));
+
+  // generic fields
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(encode)]
+  struct Tagged<T> { id: u8, value: T }
+
+  #[derive(Debug, Clone, PartialEq, Tuplike)]
+  #[tuplike(encode)]
+  enum Either<L, R> { Left(L), Right { value: R, flag: bool } }
+
+  let tagged = Tagged { id: 1, value: vec![-1i16] };
+  assert_eq!(tagged.to_bytes(), vec![1, 1, 1]);
+  assert_eq!(Tagged::from_bytes(&tagged.to_bytes()).unwrap(), tagged);
+
+  let either: Either<u8, String> = Either::Right { value: "r".to_string(), flag: true };
+  assert_eq!(either.to_bytes(), vec![1, 1, b'r', 1]);
+  assert_eq!(Either::from_bytes(&either.to_bytes()).unwrap(), either);
+}